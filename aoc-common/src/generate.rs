@@ -0,0 +1,69 @@
+use std::ops::Range;
+
+use crate::Solution;
+
+// Solutions that can make up valid inputs of their own, about `size` lines
+// or entries big. The same seed always gives the same input.
+pub trait Generate: Solution {
+    fn generate(size: usize, rng: &mut Rng) -> String;
+}
+
+// SplitMix64, small and fast, and unlike most library generators its output
+// for a seed never changes between versions
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // Panics on an empty range
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        assert!(!range.is_empty(), "Can't pick from the empty range {range:?}");
+        let width = range.end.abs_diff(range.start);
+        range.start.wrapping_add((self.next_u64() % width) as i64)
+    }
+
+    // A number in 0..n
+    pub fn below(&mut self, n: usize) -> usize {
+        self.range(0..n as i64) as usize
+    }
+
+    // True with a probability of `p`
+    pub fn chance(&mut self, p: f64) -> bool {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64 <= p
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[test]
+fn test_rng() {
+    let mut a = Rng::new(7);
+    let mut b = Rng::new(7);
+
+    let numbers = (0..100).map(|_| a.range(-3..4)).collect::<Vec<i64>>();
+
+    assert_eq!(numbers, (0..100).map(|_| b.range(-3..4)).collect::<Vec<i64>>());
+    assert!(numbers.iter().all(|n| (-3..4).contains(n)));
+    assert!((-3..4).all(|n| numbers.contains(&n)));
+}
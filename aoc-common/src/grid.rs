@@ -0,0 +1,217 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::ParseError;
+
+// (row, column), both counted from the top left
+pub type Pos = (usize, usize);
+
+const OFFSETS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+const OFFSETS_8: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1), (0, -1)];
+
+// Rectangular grid stored row by row. Indexing out of bounds panics instead
+// of wrapping onto the next row, use `get` when a position may be outside.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(rows: usize, cols: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            rows,
+            cols,
+            cells: vec![fill; rows * cols],
+        }
+    }
+
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..rows).flat_map(|r| (0..cols).map(move |c| (r, c))).map(&mut f).collect();
+        Grid { rows, cols, cells }
+    }
+
+    // None when the rows are not all the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let cols = rows.first().map_or(0, Vec::len);
+
+        if rows.iter().any(|row| row.len() != cols) {
+            return None;
+        }
+
+        Some(Grid {
+            rows: rows.len(),
+            cols,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    // Builds a grid from a character map, one row per line. Every line must
+    // be as long as the first one.
+    pub fn parse_chars(input: &str, mut f: impl FnMut(Pos, char) -> Result<T, ParseError>) -> Result<Self, ParseError> {
+        let lines = input.lines().collect::<Vec<&str>>();
+        let cols = lines.first().map_or(0, |l| l.chars().count());
+
+        if cols == 0 {
+            return Err(ParseError::new("The map is empty"));
+        }
+
+        let mut cells = Vec::with_capacity(lines.len() * cols);
+
+        for (r, line) in lines.iter().enumerate() {
+            let len = line.chars().count();
+            if len != cols {
+                return Err(ParseError::in_line(r + 1, line, format!("Expected {cols} columns like the first row, found {len}")));
+            }
+
+            for (c, ch) in line.chars().enumerate() {
+                let cell = f((r, c), ch).map_err(|e| match e.line() {
+                    Some(_) => e,
+                    None => e.with_line(r + 1).with_snippet(*line).with_span(c + 1, 1),
+                })?;
+                cells.push(cell);
+            }
+        }
+
+        Ok(Grid {
+            rows: lines.len(),
+            cols,
+            cells,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, (r, c): Pos) -> bool {
+        r < self.rows && c < self.cols
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[pos.0 * self.cols + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.cols + pos.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |r| (0..cols).map(move |c| (r, c)))
+    }
+
+    // Every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn row(&self, r: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(r < self.rows, "Row {r} is outside of a grid with {} rows", self.rows);
+        self.cells[r * self.cols..(r + 1) * self.cols].iter()
+    }
+
+    pub fn col(&self, c: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(c < self.cols, "Column {c} is outside of a grid with {} columns", self.cols);
+        self.cells.iter().skip(c).step_by(self.cols)
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.cols.max(1)).take(self.rows)
+    }
+
+    // Positions above, right, below and left of `pos` that are inside the grid
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.offset_positions(pos, &OFFSETS_4)
+    }
+
+    // Like `neighbours4`, but including the diagonals
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.offset_positions(pos, &OFFSETS_8)
+    }
+
+    fn offset_positions<'a>(&'a self, (r, c): Pos, offsets: &'a [(isize, isize)]) -> impl Iterator<Item = Pos> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |&(dr, dc)| Some((r.checked_add_signed(dr)?, c.checked_add_signed(dc)?)))
+            .filter(|&p| self.contains(p))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!("{pos:?} is outside of a {}x{} grid", self.rows, self.cols),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("{pos:?} is outside of a {rows}x{cols} grid"),
+        }
+    }
+}
+
+// One line per row, cells written next to each other
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (r, row) in self.iter_rows().enumerate() {
+            if r != 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                cell.fmt(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_grid() {
+    let grid = Grid::parse_chars("abc\ndef", |_, c| Ok(c)).unwrap();
+
+    assert_eq!((grid.rows(), grid.cols()), (2, 3));
+    assert_eq!(grid[(1, 2)], 'f');
+    assert_eq!(grid.get((2, 0)), None);
+    assert_eq!(grid.col(1).collect::<String>(), "be");
+    assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<Pos>>(), vec![(0, 1), (1, 0)]);
+    assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+    assert_eq!(grid.to_string(), "abc\ndef");
+    assert!(Grid::parse_chars("abc\nde", |_, c| Ok(c)).is_err());
+}
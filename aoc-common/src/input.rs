@@ -0,0 +1,105 @@
+use std::{
+    fs::{read_dir, read_to_string},
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+// Real inputs can be kept outside of the repository, in
+// $AOC_INPUT_DIR/<crate directory name>/. Files there take precedence over
+// the ones in the crate.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum InputKind {
+    Example,
+    Real,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputFile {
+    // Relative to the crate directory
+    pub name: String,
+    pub path: PathBuf,
+    pub kind: InputKind,
+}
+
+// Resolves relative to the calling crate, wherever the tests or binary run from
+#[macro_export]
+macro_rules! input_path {
+    ($name:expr) => {
+        $crate::input::resolve(env!("CARGO_MANIFEST_DIR"), $name)
+    };
+}
+
+fn override_dir(crate_dir: &Path) -> Option<PathBuf> {
+    let dir = std::env::var_os(INPUT_DIR_VAR)?;
+    Some(PathBuf::from(dir).join(crate_dir.file_name()?))
+}
+
+pub fn resolve(crate_dir: impl AsRef<Path>, name: &str) -> PathBuf {
+    let crate_dir = crate_dir.as_ref();
+
+    match override_dir(crate_dir).map(|dir| dir.join(name)) {
+        Some(path) if path.exists() => path,
+        _ => crate_dir.join(name),
+    }
+}
+
+pub fn read(crate_dir: impl AsRef<Path>, name: &str) -> io::Result<String> {
+    read_to_string(resolve(crate_dir, name))
+}
+
+pub fn read_stdin() -> io::Result<String> {
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
+    Ok(input)
+}
+
+// Example inputs are the ones named after the puzzle's examples
+fn input_kind(name: &str) -> InputKind {
+    if ["test", "small", "example"].iter().any(|s| name.contains(s)) {
+        InputKind::Example
+    } else {
+        InputKind::Real
+    }
+}
+
+// Every .txt file in the crate directory or its src/, and in the override
+// directory, examples first
+pub fn list_inputs(crate_dir: impl AsRef<Path>) -> Vec<InputFile> {
+    let crate_dir = crate_dir.as_ref();
+    let mut inputs: Vec<InputFile> = Vec::new();
+
+    let roots = override_dir(crate_dir).into_iter().chain([crate_dir.to_path_buf()]);
+
+    for root in roots {
+        for sub in ["", "src"] {
+            let Ok(entries) = read_dir(root.join(sub)) else {
+                continue;
+            };
+
+            for path in entries.filter_map(|e| Some(e.ok()?.path())) {
+                if path.extension().is_none_or(|e| e != "txt") {
+                    continue;
+                }
+
+                let Some(file_name) = path.file_name().and_then(|n| n.to_str()) else {
+                    continue;
+                };
+
+                let name = if sub.is_empty() { file_name.to_string() } else { format!("{sub}/{file_name}") };
+
+                if inputs.iter().all(|i| i.name != name) {
+                    inputs.push(InputFile {
+                        kind: input_kind(file_name),
+                        name,
+                        path,
+                    });
+                }
+            }
+        }
+    }
+
+    inputs.sort_by(|a, b| (a.kind, &a.name).cmp(&(b.kind, &b.name)));
+    inputs
+}
@@ -0,0 +1,81 @@
+use std::{fmt::Display, str::FromStr};
+
+pub mod generate;
+pub mod grid;
+pub mod input;
+mod parse_error;
+pub mod render;
+pub mod sparse_grid;
+pub mod stream;
+
+pub use grid::Grid;
+pub use parse_error::ParseError;
+pub use sparse_grid::SparseGrid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::A => "A".fmt(f),
+            Part::B => "B".fmt(f),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" | "A" => Ok(Part::A),
+            "b" | "B" => Ok(Part::B),
+            s => Err(format!("{s} is not a part, expected a or b")),
+        }
+    }
+}
+
+// Implemented once per day, so the runner can dispatch to any of them.
+// Parsing is kept apart from solving so both parts can share the parsed input.
+pub trait Solution {
+    type Input;
+    type AnswerA: Display;
+    type AnswerB: Display;
+
+    // Parts that have no solution yet, which answer `Unsolved`. Solving them
+    // is an error rather than an answer.
+    const UNSOLVED: &'static [Part] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_a(input: &Self::Input) -> Self::AnswerA;
+    fn part_b(input: &Self::Input) -> Self::AnswerB;
+
+    fn solve(part: Part, input: &str) -> Result<String, ParseError> {
+        if Self::UNSOLVED.contains(&part) {
+            return Err(ParseError::new(format!("Part {part} is not solved yet")));
+        }
+
+        let input = Self::parse(input).map_err(|e| e.with_source(input))?;
+
+        let answer = match part {
+            Part::A => Self::part_a(&input).to_string(),
+            Part::B => Self::part_b(&input).to_string(),
+        };
+
+        Ok(answer)
+    }
+}
+
+// The answer to a part listed in `Solution::UNSOLVED`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        "not solved yet".fmt(f)
+    }
+}
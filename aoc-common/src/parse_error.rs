@@ -0,0 +1,178 @@
+use std::{error::Error, fmt::Display};
+
+// Returned by every day's parser instead of panicking on malformed input.
+// Carries as much of the location as the parser knows, so it can be rendered
+// as a diagnostic pointing at the offending part of the input:
+//
+// input.txt:3:5: "x" is not a number
+//   |
+// 3 | 100x
+//   |    ^
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    file: Option<String>,
+    line: Option<usize>,
+    column: Option<usize>,
+    width: usize,
+    snippet: Option<String>,
+    message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        ParseError {
+            file: None,
+            line: None,
+            column: None,
+            width: 1,
+            snippet: None,
+            message: message.into(),
+        }
+    }
+
+    // Line numbers start at 1, as they would in an editor
+    pub fn at_line(line: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            line: Some(line),
+            ..ParseError::new(message)
+        }
+    }
+
+    // Points at the whole offending line
+    pub fn in_line(line: usize, text: &str, message: impl Into<String>) -> Self {
+        ParseError::at_line(line, message).with_snippet(text)
+    }
+
+    // Points at `token` within `text`. `token` should be a slice of `text`,
+    // otherwise its first occurrence is used.
+    pub fn at_token(line: usize, text: &str, token: &str, message: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(text.as_ptr() as usize)
+            .filter(|offset| offset + token.len() <= text.len())
+            .or_else(|| text.find(token));
+
+        let error = ParseError::in_line(line, text, message);
+
+        match offset {
+            Some(offset) => {
+                let column = text[..offset].chars().count() + 1;
+                error.with_span(column, token.chars().count())
+            }
+            None => error,
+        }
+    }
+
+    // For errors raised by helpers that parse a single line without knowing
+    // where it came from
+    pub fn with_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    // Columns start at 1 and count characters, not bytes
+    pub fn with_span(mut self, column: usize, width: usize) -> Self {
+        self.column = Some(column);
+        self.width = width.max(1);
+        self
+    }
+
+    pub fn with_snippet(mut self, snippet: impl Into<String>) -> Self {
+        self.snippet = Some(snippet.into());
+        self
+    }
+
+    // Fills in the offending line from the whole input, for parsers that
+    // only know the line number
+    pub fn with_source(self, input: &str) -> Self {
+        match (self.line, &self.snippet) {
+            (Some(line), None) => match input.lines().nth(line.wrapping_sub(1)) {
+                Some(text) => self.with_snippet(text),
+                None => self,
+            },
+            _ => self,
+        }
+    }
+
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    pub fn snippet(&self) -> Option<&str> {
+        self.snippet.as_deref()
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    // file:line:column when the file is known, as editors and terminals
+    // can jump to it, otherwise spelled out
+    fn location(&self) -> Option<String> {
+        match (&self.file, self.line, self.column) {
+            (Some(file), Some(line), Some(column)) => Some(format!("{file}:{line}:{column}")),
+            (Some(file), Some(line), None) => Some(format!("{file}:{line}")),
+            (Some(file), None, _) => Some(file.clone()),
+            (None, Some(line), Some(column)) => Some(format!("Line {line}, column {column}")),
+            (None, Some(line), None) => Some(format!("Line {line}")),
+            (None, None, _) => None,
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.location() {
+            Some(location) => write!(f, "{location}: {}", self.message)?,
+            None => write!(f, "{}", self.message)?,
+        }
+
+        let (Some(line), Some(snippet)) = (self.line, &self.snippet) else {
+            return Ok(());
+        };
+
+        let number = line.to_string();
+        let gutter = " ".repeat(number.len());
+
+        write!(f, "\n{gutter} |\n{number} | {snippet}")?;
+
+        if let Some(column) = self.column {
+            // Keep tabs so the caret lines up with the snippet above it
+            let indent: String = snippet
+                .chars()
+                .take(column.saturating_sub(1))
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect();
+            let padding = " ".repeat(column.saturating_sub(1).saturating_sub(indent.chars().count()));
+
+            write!(f, "\n{gutter} | {indent}{padding}{}", "^".repeat(self.width))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+#[test]
+fn test_caret_diagnostic() {
+    let text = "move 1 from x to 2";
+    let error = ParseError::at_token(3, text, &text[12..13], "Stack number does not exist").with_file("input.txt");
+
+    assert_eq!(
+        error.to_string(),
+        "input.txt:3:13: Stack number does not exist\n  |\n3 | move 1 from x to 2\n  |             ^"
+    );
+}
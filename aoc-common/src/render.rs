@@ -0,0 +1,198 @@
+use std::fmt::Display;
+
+use crate::{sparse_grid::Bounds, Grid, Part, Solution, SparseGrid};
+
+pub type Rgb = [u8; 3];
+
+// Behind blank cells in images
+pub const BACKGROUND: Rgb = [15, 15, 35];
+
+// One character of a picture. Blank cells have no colour and are left out of
+// ANSI output and drawn as the background in images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub ch: char,
+    pub color: Option<Rgb>,
+}
+
+impl Cell {
+    pub const fn new(ch: char, color: Rgb) -> Self {
+        Cell { ch, color: Some(color) }
+    }
+
+    pub const fn blank(ch: char) -> Self {
+        Cell { ch, color: None }
+    }
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.ch.fmt(f)
+    }
+}
+
+// Printing a frame gives the plain ASCII picture
+pub type Frame = Grid<Cell>;
+
+pub trait Render {
+    fn render(&self) -> Frame;
+}
+
+// Solutions that can show their work, one frame for every `every` steps of
+// the part's simulation and always the last one
+pub trait Visualize: Solution {
+    fn frames(input: &Self::Input, part: Part, every: usize) -> Vec<Frame>;
+}
+
+// Draws `region` of a sparse grid, `f` is given None for unwritten cells
+pub fn render_region<T>(grid: &SparseGrid<T>, region: Bounds, f: impl Fn(Option<&T>) -> Cell) -> Frame {
+    let rows = region.rows().count();
+    let cols = region.cols().count();
+
+    Frame::from_fn(rows, cols, |(r, c)| f(grid.get((region.min.0 + r as i64, region.min.1 + c as i64))))
+}
+
+// Draws every written cell of a sparse grid, fitted to its bounds
+pub fn render_fitted<T>(grid: &SparseGrid<T>, f: impl Fn(Option<&T>) -> Cell) -> Frame {
+    match grid.bounds() {
+        Some(bounds) => render_region(grid, bounds, f),
+        None => Frame::new(0, 0, Cell::blank(' ')),
+    }
+}
+
+// 24-bit colour escape codes, for terminals that support them
+pub fn to_ansi(frame: &Frame) -> String {
+    frame
+        .iter_rows()
+        .map(|row| {
+            let mut line = String::new();
+            for cell in row {
+                match cell.color {
+                    Some([r, g, b]) => line.push_str(&format!("\x1b[38;2;{r};{g};{b}m{}", cell.ch)),
+                    None => line.push_str(&format!("\x1b[0m{}", cell.ch)),
+                }
+            }
+            line.push_str("\x1b[0m");
+            line
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+// Records every `every`th step of a simulation, `finish` adds the last step
+// if it was skipped
+pub struct Recorder {
+    every: usize,
+    step: usize,
+    frames: Vec<Frame>,
+}
+
+impl Recorder {
+    pub fn new(every: usize) -> Self {
+        Recorder {
+            every: every.max(1),
+            step: 0,
+            frames: Vec::new(),
+        }
+    }
+
+    // `render` is only called for the steps that are kept
+    pub fn record(&mut self, render: impl FnOnce() -> Frame) {
+        if self.step.is_multiple_of(self.every) {
+            self.frames.push(render());
+        }
+        self.step += 1;
+    }
+
+    pub fn finish(mut self, render: impl FnOnce() -> Frame) -> Vec<Frame> {
+        if self.step == 0 || !(self.step - 1).is_multiple_of(self.every) {
+            self.frames.push(render());
+        }
+        self.frames
+    }
+}
+
+// Pixels of `frame` row by row, `scale` pixels per cell, padded with the
+// background to `rows` by `cols` cells
+pub fn pixels(frame: &Frame, rows: usize, cols: usize, scale: usize) -> Vec<Rgb> {
+    let (width, height) = (cols * scale, rows * scale);
+    let mut pixels = Vec::with_capacity(width * height);
+
+    for y in 0..height {
+        for x in 0..width {
+            pixels.push(frame.get((y / scale, x / scale)).and_then(|c| c.color).unwrap_or(BACKGROUND));
+        }
+    }
+
+    pixels
+}
+
+#[cfg(feature = "image")]
+pub use image::{write_gif, write_png};
+
+#[cfg(feature = "image")]
+mod image {
+    use std::{fs::File, io, path::Path};
+
+    use super::{pixels, Frame};
+
+    fn size(frames: &[Frame], scale: usize) -> io::Result<(usize, usize, u16, u16)> {
+        let rows = frames.iter().map(Frame::rows).max().unwrap_or(0);
+        let cols = frames.iter().map(Frame::cols).max().unwrap_or(0);
+
+        let dimension = |cells: usize| {
+            u16::try_from(cells * scale)
+                .ok()
+                .filter(|&d| d > 0)
+                .ok_or_else(|| io::Error::other(format!("Can't make an image {} pixels across", cells * scale)))
+        };
+
+        Ok((rows, cols, dimension(cols)?, dimension(rows)?))
+    }
+
+    pub fn write_png(frame: &Frame, path: &Path, scale: usize) -> io::Result<()> {
+        let (rows, cols, width, height) = size(std::slice::from_ref(frame), scale.max(1))?;
+
+        let mut encoder = png::Encoder::new(File::create(path)?, width.into(), height.into());
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&pixels(frame, rows, cols, scale.max(1)).concat()))
+            .map_err(io::Error::other)
+    }
+
+    // Frames of different sizes are anchored at the top left and padded to
+    // the largest one
+    pub fn write_gif(frames: &[Frame], path: &Path, scale: usize, delay_ms: u16) -> io::Result<()> {
+        let (rows, cols, width, height) = size(frames, scale.max(1))?;
+
+        let mut encoder = gif::Encoder::new(File::create(path)?, width, height, &[]).map_err(io::Error::other)?;
+        encoder.set_repeat(gif::Repeat::Infinite).map_err(io::Error::other)?;
+
+        for frame in frames {
+            let mut image = gif::Frame::from_rgb(width, height, &pixels(frame, rows, cols, scale.max(1)).concat());
+            // In hundredths of a second
+            image.delay = delay_ms / 10;
+            encoder.write_frame(&image).map_err(io::Error::other)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_render() {
+    let mut grid = SparseGrid::new();
+    grid.insert((-1, 2), '#');
+    grid.insert((0, 0), 'o');
+
+    let frame = render_fitted(&grid, |c| match c {
+        Some(&c) => Cell::new(c, [255, 0, 0]),
+        None => Cell::blank('.'),
+    });
+
+    assert_eq!(frame.to_string(), "..#\no..");
+    assert_eq!(to_ansi(&frame).lines().next(), Some("\x1b[0m.\x1b[0m.\x1b[38;2;255;0;0m#\x1b[0m"));
+}
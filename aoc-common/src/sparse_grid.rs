@@ -0,0 +1,190 @@
+use std::{collections::HashMap, fmt::Display, ops::RangeInclusive};
+
+// (row, column), unbounded in every direction
+pub type Pos = (i64, i64);
+
+// Inclusive rectangle of positions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub min: Pos,
+    pub max: Pos,
+}
+
+impl Bounds {
+    pub fn new((r1, c1): Pos, (r2, c2): Pos) -> Self {
+        Bounds {
+            min: (r1.min(r2), c1.min(c2)),
+            max: (r1.max(r2), c1.max(c2)),
+        }
+    }
+
+    pub fn contains(&self, (r, c): Pos) -> bool {
+        self.rows().contains(&r) && self.cols().contains(&c)
+    }
+
+    pub fn rows(&self) -> RangeInclusive<i64> {
+        self.min.0..=self.max.0
+    }
+
+    pub fn cols(&self) -> RangeInclusive<i64> {
+        self.min.1..=self.max.1
+    }
+
+    // Smallest bounds containing both these bounds and `pos`
+    pub fn including(&self, (r, c): Pos) -> Self {
+        Bounds {
+            min: (self.min.0.min(r), self.min.1.min(c)),
+            max: (self.max.0.max(r), self.max.1.max(c)),
+        }
+    }
+
+    pub fn expanded(&self, margin: i64) -> Self {
+        Bounds {
+            min: (self.min.0 - margin, self.min.1 - margin),
+            max: (self.max.0 + margin, self.max.1 + margin),
+        }
+    }
+}
+
+// Only stores the cells that were written. The bounds grow to include every
+// written cell and never shrink, so a rendering stays the same size after
+// cells are removed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Pos, T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, pos: Pos, value: T) -> Option<T> {
+        self.include(pos);
+        self.cells.insert(pos, value)
+    }
+
+    // Like `HashMap::entry(pos).or_insert_with(f)`
+    pub fn get_or_insert_with(&mut self, pos: Pos, f: impl FnOnce() -> T) -> &mut T {
+        self.include(pos);
+        self.cells.entry(pos).or_insert_with(f)
+    }
+
+    pub fn remove(&mut self, pos: Pos) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    // None until the first cell is written
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    // Written cells, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    // Written cells inside `region`, in no particular order
+    pub fn region(&self, region: Bounds) -> impl Iterator<Item = (Pos, &T)> {
+        self.iter().filter(move |&(pos, _)| region.contains(pos))
+    }
+
+    // Draws `region` row by row, top to bottom, one character per cell
+    pub fn render_region(&self, region: Bounds, f: impl Fn(Pos, Option<&T>) -> char) -> String {
+        region
+            .rows()
+            .map(|r| region.cols().map(|c| f((r, c), self.get((r, c)))).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    // Draws every written cell, fitted to the bounds
+    pub fn render(&self, f: impl Fn(Pos, Option<&T>) -> char) -> String {
+        match self.bounds {
+            Some(bounds) => self.render_region(bounds, f),
+            None => String::new(),
+        }
+    }
+
+    fn include(&mut self, pos: Pos) {
+        self.bounds = Some(match self.bounds {
+            Some(bounds) => bounds.including(pos),
+            None => Bounds::new(pos, pos),
+        });
+    }
+}
+
+impl<T> FromIterator<(Pos, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Pos, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        iter.into_iter().for_each(|(pos, value)| {
+            grid.insert(pos, value);
+        });
+        grid
+    }
+}
+
+// Fitted to the bounds, with `.` for cells that were never written
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(bounds) = self.bounds else {
+            return Ok(());
+        };
+
+        for r in bounds.rows() {
+            if r != bounds.min.0 {
+                writeln!(f)?;
+            }
+
+            for c in bounds.cols() {
+                match self.get((r, c)) {
+                    Some(value) => value.fmt(f)?,
+                    None => '.'.fmt(f)?,
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_sparse_grid() {
+    let mut grid = SparseGrid::new();
+    grid.insert((-2, 3), '#');
+    grid.insert((1, -1), 'o');
+
+    assert_eq!(grid.bounds(), Some(Bounds::new((-2, -1), (1, 3))));
+    assert_eq!(grid.region(Bounds::new((0, -5), (5, 5))).count(), 1);
+    assert_eq!(grid.to_string(), "....#\n.....\n.....\no....");
+}
@@ -0,0 +1,69 @@
+use std::io::{self, BufRead};
+
+use crate::{ParseError, Part, Solution};
+
+#[test]
+fn test_line_parser() {
+    let parse = |line_number: usize, line: &str| line.parse::<i32>().map_err(|e| ParseError::at_line(line_number, e.to_string()));
+    let lines = || "1\n2\nthree\n4".as_bytes().lines();
+
+    assert_eq!(LineParser::new(lines(), parse).take(2).collect::<Result<Vec<i32>, ParseError>>(), Ok(vec![1, 2]));
+    assert_eq!(LineParser::new(lines(), parse).collect::<Result<Vec<i32>, ParseError>>().unwrap_err().line(), Some(3));
+}
+
+// Solutions that can parse and solve their input a line at a time, keeping
+// only what the part needs, for inputs too big to read whole
+pub trait Stream: Solution {
+    fn stream_a(reader: impl BufRead) -> Result<Self::AnswerA, ParseError>;
+    fn stream_b(reader: impl BufRead) -> Result<Self::AnswerB, ParseError>;
+
+    fn solve_stream(part: Part, reader: impl BufRead) -> Result<String, ParseError> {
+        let answer = match part {
+            Part::A => Self::stream_a(reader)?.to_string(),
+            Part::B => Self::stream_b(reader)?.to_string(),
+        };
+
+        Ok(answer)
+    }
+}
+
+// Parses lines in turn, such as the ones `BufRead::lines` gives, with `parse`,
+// which is given the number of each line for its errors
+pub struct LineParser<I, F> {
+    line_number: usize,
+    source: I,
+    parse: F,
+}
+
+impl<I, F, T> LineParser<I, F>
+where
+    I: Iterator<Item = io::Result<String>>,
+    F: FnMut(usize, &str) -> Result<T, ParseError>,
+{
+    pub fn new(source: I, parse: F) -> LineParser<I, F> {
+        LineParser {
+            line_number: 0,
+            source,
+            parse,
+        }
+    }
+}
+
+impl<I, F, T> Iterator for LineParser<I, F>
+where
+    I: Iterator<Item = io::Result<String>>,
+    F: FnMut(usize, &str) -> Result<T, ParseError>,
+{
+    type Item = Result<T, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.line_number += 1;
+
+        let x = match self.source.next()? {
+            Err(err) => Err(ParseError::at_line(self.line_number, err.to_string())),
+            Ok(line) => (self.parse)(self.line_number, &line),
+        };
+
+        Some(x)
+    }
+}
@@ -0,0 +1,231 @@
+use aoc_common::{Grid, Unsolved};
+use d10_cathode_ray_tube::Operation;
+use d11_monkey_in_the_middle::monkey::Monkey;
+use d12_hill_climbing_algorithm::Heightmap;
+use d13_distress_signal::packet::Packet;
+use d14_regolith_resovoir::Cave;
+use d15_beacon_exclusion_zone::Report;
+use d16_proboscidea_volcanium::Valve;
+use d2_rockpaper_scissors::{
+    cipher::Letter,
+    rules::{Shape, ROCK_PAPER_SCISSORS},
+};
+use d3_rucksack_reorganization::rucksack::Rucksack;
+use d5_supply_stacks::{Procedure, Stacks};
+use d7_no_space_left_on_device::directory_manager::DirectoryManager;
+use d9_rope_bridge::{direction::Direction, r#move::Move};
+use pyo3::{
+    exceptions::PyNotImplementedError,
+    prelude::*,
+    types::{PyDict, PyList, PyTuple},
+};
+
+// Parsed inputs and answers as plain Python values, so nothing on the
+// Python side needs to know about the Rust types
+pub trait ToPython {
+    fn to_python<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>>;
+}
+
+macro_rules! native {
+    ($($t:ty),*) => {
+        $(impl ToPython for $t {
+            fn to_python<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+                Ok(self.into_pyobject(py)?.into_any())
+            }
+        })*
+    };
+}
+
+native!(i32, i64, u8, u64, u128, usize, String, &str);
+
+impl ToPython for Py<PyAny> {
+    fn to_python<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        Ok(self.bind(py).clone())
+    }
+}
+
+// Unsolved parts raise rather than answer
+impl ToPython for Unsolved {
+    fn to_python<'py>(&self, _py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        Err(PyNotImplementedError::new_err("Not solved yet"))
+    }
+}
+
+impl<T: ToPython> ToPython for Vec<T> {
+    fn to_python<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        list(py, self)
+    }
+}
+
+impl<A: ToPython, B: ToPython> ToPython for (A, B) {
+    fn to_python<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        Ok(PyTuple::new(py, [self.0.to_python(py)?, self.1.to_python(py)?])?.into_any())
+    }
+}
+
+impl<T: ToPython> ToPython for Grid<T> {
+    fn to_python<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let rows = self.iter_rows().map(|row| list(py, row)).collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new(py, rows)?.into_any())
+    }
+}
+
+fn list<'py>(py: Python<'py>, items: &[impl ToPython]) -> PyResult<Bound<'py, PyAny>> {
+    let items = items.iter().map(|i| i.to_python(py)).collect::<PyResult<Vec<_>>>()?;
+    Ok(PyList::new(py, items)?.into_any())
+}
+
+fn dict<'py>(py: Python<'py>, items: &[(&str, &dyn ToPython)]) -> PyResult<Bound<'py, PyAny>> {
+    let d = PyDict::new(py);
+    for (key, value) in items {
+        d.set_item(key, value.to_python(py)?)?;
+    }
+    Ok(d.into_any())
+}
+
+// Letters are given as the shapes they stand for in part A
+impl ToPython for Letter {
+    fn to_python<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        ROCK_PAPER_SCISSORS.name(Shape::from(*self)).to_python(py)
+    }
+}
+
+impl ToPython for Rucksack {
+    fn to_python<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        self.items().to_python(py)
+    }
+}
+
+// Pairs of ranges, as ((2, 4), (6, 8))
+impl ToPython for (u8, u8, u8, u8) {
+    fn to_python<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        ((self.0, self.1), (self.2, self.3)).to_python(py)
+    }
+}
+
+// Stacks are indexed from 0, like the list of stacks
+impl ToPython for Procedure {
+    fn to_python<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let stacks = self
+            .stacks()
+            .crates()
+            .iter()
+            .map(|stack| stack.iter().map(char::to_string).collect())
+            .collect::<Vec<Vec<String>>>();
+        let moves = self
+            .moves()
+            .iter()
+            .map(|mv| Ok(dict(py, &[("count", &mv.count()), ("from", &mv.from()), ("to", &mv.to())])?.unbind()))
+            .collect::<PyResult<Vec<Py<PyAny>>>>()?;
+
+        dict(py, &[("stacks", &stacks), ("moves", &moves)])
+    }
+}
+
+// The crates on top of each stack
+impl ToPython for Stacks {
+    fn to_python<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        self.to_string().to_python(py)
+    }
+}
+
+// Total size of every directory by its path
+impl ToPython for DirectoryManager {
+    fn to_python<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let sizes = PyDict::new(py);
+        for (path, size) in self.compute_dir_paths().into_iter().zip(self.compute_dir_sizes()) {
+            sizes.set_item(path, size)?;
+        }
+        Ok(sizes.into_any())
+    }
+}
+
+impl ToPython for Move {
+    fn to_python<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let direction = match self.direction {
+            Direction::Right => "R",
+            Direction::Left => "L",
+            Direction::Up => "U",
+            Direction::Down => "D",
+        };
+        (direction, self.steps).to_python(py)
+    }
+}
+
+// ("noop", None) or ("addx", n)
+impl ToPython for Operation {
+    fn to_python<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let (name, argument) = match self {
+            Operation::Noop => ("noop", None),
+            Operation::Addx(n) => ("addx", Some(*n)),
+        };
+        Ok(PyTuple::new(py, [name.into_pyobject(py)?.into_any(), argument.into_pyobject(py)?])?.into_any())
+    }
+}
+
+impl ToPython for Monkey {
+    fn to_python<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let (if_true, if_false) = self.target_monkey_ids();
+        dict(
+            py,
+            &[
+                ("id", &self.id()),
+                ("items", &self.items().to_vec()),
+                ("operation", &self.operation().to_string()),
+                ("test", &self.test_number()),
+                ("if_true", &if_true),
+                ("if_false", &if_false),
+            ],
+        )
+    }
+}
+
+impl ToPython for Heightmap {
+    fn to_python<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        dict(py, &[("heights", self.grid()), ("start", &self.start()), ("end", &self.end())])
+    }
+}
+
+// Numbers and nested lists, the same as the packet's JSON
+impl ToPython for Packet {
+    fn to_python<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match self {
+            Packet::Number(n) => n.to_python(py),
+            Packet::List(packets) => list(py, packets),
+        }
+    }
+}
+
+impl ToPython for Cave {
+    fn to_python<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        dict(py, &[("sand_spawner", &self.sand_spawner()), ("rocks", &self.rocks())])
+    }
+}
+
+impl ToPython for Report {
+    fn to_python<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let sensors = self
+            .zone()
+            .sensors()
+            .iter()
+            .map(|s| Ok(dict(py, &[("sensor", &s.sensor_pos), ("beacon", &s.beacon_pos)])?.unbind()))
+            .collect::<PyResult<Vec<Py<PyAny>>>>()?;
+
+        dict(py, &[("sensors", &sensors), ("row", &self.row()), ("range", &self.range())])
+    }
+}
+
+// Tunnels lead to valves by name rather than by index
+impl ToPython for Vec<Valve> {
+    fn to_python<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let valves = self
+            .iter()
+            .map(|v| {
+                let leads_to = v.leads_to.iter().map(|&id| self[id].name.clone()).collect::<Vec<String>>();
+                dict(py, &[("name", &v.name), ("flow_rate", &v.flow_rate), ("leads_to", &leads_to)])
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        Ok(PyList::new(py, valves)?.into_any())
+    }
+}
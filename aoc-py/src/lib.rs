@@ -0,0 +1,90 @@
+use aoc_common::{Part, Solution};
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    prelude::*,
+    types::{PyCFunction, PyDict, PyTuple},
+};
+
+mod convert;
+
+use convert::ToPython;
+
+// Inputs and answers both go through ToPython, so every day can be added
+// with the same three functions
+trait Day: Solution<Input: ToPython, AnswerA: ToPython, AnswerB: ToPython> {}
+
+impl<S> Day for S where S: Solution<Input: ToPython, AnswerA: ToPython, AnswerB: ToPython> {}
+
+fn parse<S: Day>(input: &str) -> PyResult<S::Input> {
+    S::parse(input).map_err(|e| PyValueError::new_err(e.with_source(input).to_string()))
+}
+
+fn solve<S: Day>(py: Python<'_>, part: Part, input: &str) -> PyResult<Py<PyAny>> {
+    let parsed = parse::<S>(input)?;
+
+    let answer = match part {
+        Part::A => S::part_a(&parsed).to_python(py)?,
+        Part::B => S::part_b(&parsed).to_python(py)?,
+    };
+
+    Ok(answer.unbind())
+}
+
+// The puzzle input, the only argument every function takes
+fn input_arg(args: &Bound<'_, PyTuple>, kwargs: Option<&Bound<'_, PyDict>>) -> PyResult<String> {
+    match (args.len(), kwargs.and_then(|k| k.get_item("input").transpose())) {
+        (1, None) => args.get_item(0)?.extract(),
+        (0, Some(input)) => input?.extract(),
+        _ => Err(PyTypeError::new_err("Expected the puzzle input as the only argument")),
+    }
+}
+
+// A submodule such as `aoc_py.d13`, with parse, part_a and part_b
+fn add_day<S: Day + 'static>(parent: &Bound<'_, PyModule>, name: &str) -> PyResult<()> {
+    let py = parent.py();
+    let day = PyModule::new(py, name)?;
+
+    let parse = PyCFunction::new_closure(
+        py,
+        Some(c"parse"),
+        Some(c"parse(input)\n--\n\nThe parsed puzzle input as lists, tuples, dicts and numbers"),
+        |args, kwargs| -> PyResult<Py<PyAny>> {
+            let py = args.py();
+            Ok(parse::<S>(&input_arg(args, kwargs)?)?.to_python(py)?.unbind())
+        },
+    )?;
+    let part_a = PyCFunction::new_closure(py, Some(c"part_a"), Some(c"part_a(input)\n--\n\nThe answer to part A"), |args, kwargs| {
+        solve::<S>(args.py(), Part::A, &input_arg(args, kwargs)?)
+    })?;
+    let part_b = PyCFunction::new_closure(py, Some(c"part_b"), Some(c"part_b(input)\n--\n\nThe answer to part B"), |args, kwargs| {
+        solve::<S>(args.py(), Part::B, &input_arg(args, kwargs)?)
+    })?;
+
+    day.add_function(parse)?;
+    day.add_function(part_a)?;
+    day.add_function(part_b)?;
+    parent.add_submodule(&day)
+}
+
+/// Advent of Code 2022 solutions, one submodule per day
+#[pymodule]
+fn aoc_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    add_day::<d1_calorie_counting::CalorieCounting>(m, "d1")?;
+    add_day::<d2_rockpaper_scissors::RockPaperScissors>(m, "d2")?;
+    add_day::<d3_rucksack_reorganization::RucksackReorganization>(m, "d3")?;
+    add_day::<d4_camp_cleanup::CampCleanup>(m, "d4")?;
+    add_day::<d5_supply_stacks::SupplyStacks>(m, "d5")?;
+    add_day::<d6_tuning_trouble::TuningTrouble>(m, "d6")?;
+    add_day::<d7_no_space_left_on_device::NoSpaceLeftOnDevice>(m, "d7")?;
+    add_day::<d8_treetop_tree_house::TreetopTreeHouse>(m, "d8")?;
+    add_day::<d9_rope_bridge::RopeBridge>(m, "d9")?;
+    add_day::<d10_cathode_ray_tube::CathodeRayTube>(m, "d10")?;
+    add_day::<d11_monkey_in_the_middle::MonkeyInTheMiddle>(m, "d11")?;
+    add_day::<d12_hill_climbing_algorithm::HillClimbingAlgorithm>(m, "d12")?;
+    add_day::<d13_distress_signal::DistressSignal>(m, "d13")?;
+    add_day::<d14_regolith_resovoir::RegolithReservoir>(m, "d14")?;
+    add_day::<d15_beacon_exclusion_zone::BeaconExclusionZone>(m, "d15")?;
+    add_day::<d16_proboscidea_volcanium::ProboscideaVolcanium>(m, "d16")?;
+
+    Ok(())
+}
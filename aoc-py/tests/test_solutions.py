@@ -0,0 +1,86 @@
+# Build the module into the current environment first, with
+# `maturin develop --release` from aoc-py
+import tomllib
+from pathlib import Path
+
+import pytest
+
+import aoc_py
+
+ROOT = Path(__file__).resolve().parents[2]
+
+
+def read(day, file="src/test-input.txt"):
+    (directory,) = ROOT.glob(f"d{day}-*")
+    return (directory / file).read_text()
+
+
+# Every input in the answers.toml files, skipping the ones the Rust tests skip
+def examples():
+    for answers in sorted(ROOT.glob("d*/answers.toml")):
+        expected = tomllib.loads(answers.read_text())
+        if expected["year"] != 2022:
+            continue
+        for entry in expected["input"]:
+            if "ignore" not in entry:
+                yield pytest.param(expected["day"], entry, id=f"d{expected['day']}-{entry['file']}")
+
+
+@pytest.mark.parametrize("day, expected", examples())
+def test_answers(day, expected):
+    solution = getattr(aoc_py, f"d{day}")
+    input = read(day, expected["file"])
+
+    assert str(solution.part_a(input)).strip() == expected["a"].strip()
+    if "b" in expected:
+        assert str(solution.part_b(input)).strip() == expected["b"].strip()
+
+
+def test_answers_are_python_values():
+    assert aoc_py.d13.part_a(read(13)) == 13
+    assert aoc_py.d5.part_a(read(5, "src/small-input.txt")) == "CMZ"
+
+
+def test_packets_are_nested_lists():
+    pairs = aoc_py.d13.parse(read(13))
+
+    assert len(pairs) == 8
+    assert pairs[0] == ([1, 1, 3, 1, 1], [1, 1, 5, 1, 1])
+    assert pairs[1] == ([[1], [2, 3, 4]], [[1], 4])
+    assert pairs[6] == ([[[]]], [[]])
+
+
+def test_valves_are_dicts():
+    valves = aoc_py.d16.parse(read(16))
+
+    assert valves[0] == {"name": "AA", "flow_rate": 0, "leads_to": ["DD", "II", "BB"]}
+    assert [v["name"] for v in valves if v["flow_rate"] > 0] == ["BB", "CC", "DD", "EE", "HH", "JJ"]
+
+
+def test_directory_sizes_by_path():
+    sizes = aoc_py.d7.parse(read(7, "test-input.txt"))
+
+    assert sizes == {"/": 48381165, "/a": 94853, "/a/e": 584, "/d": 24933642}
+
+
+def test_other_days_parse_to_python_values():
+    assert aoc_py.d1.parse(read(1, "src/test-elf-calories.txt")) == [6000, 4000, 11000, 24000, 10000]
+    assert aoc_py.d4.parse(read(4))[0] == ((2, 4), (6, 8))
+    assert aoc_py.d9.parse(read(9, "test-input.txt"))[0] == ("R", 4)
+    assert aoc_py.d10.parse(read(10, "src/test-input1.txt"))[:2] == [("noop", None), ("addx", 3)]
+    assert aoc_py.d12.parse(read(12))["start"] == (0, 0)
+
+    procedure = aoc_py.d5.parse(read(5, "src/small-input.txt"))
+    assert procedure["stacks"] == [["Z", "N"], ["M", "C", "D"], ["P"]]
+    assert procedure["moves"][0] == {"count": 1, "from": 1, "to": 0}
+
+    monkey = aoc_py.d11.parse(read(11, "test-input.txt"))[0]
+    assert monkey == {"id": 0, "items": [79, 98], "operation": "old * 19", "test": 23, "if_true": 2, "if_false": 3}
+
+
+def test_invalid_input_raises():
+    with pytest.raises(ValueError, match="Line 3"):
+        aoc_py.d4.parse("2-4,6-8\n2-3,4-5\n5-7,7-x\n")
+
+    with pytest.raises(TypeError):
+        aoc_py.d1.part_a()
@@ -0,0 +1,101 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{ParseError, Part, Solution};
+use serde::Serialize;
+
+// Summary of how long one step took over every iteration
+#[derive(Debug, Clone, Serialize)]
+pub struct Stats {
+    pub mean_ns: u64,
+    pub min_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    fn from_samples(samples: &[Duration]) -> Self {
+        let nanos = samples.iter().map(|d| d.as_nanos() as u64).collect::<Vec<u64>>();
+
+        Stats {
+            mean_ns: nanos.iter().sum::<u64>() / nanos.len().max(1) as u64,
+            min_ns: nanos.iter().copied().min().unwrap_or(0),
+            max_ns: nanos.iter().copied().max().unwrap_or(0),
+        }
+    }
+
+    pub fn mean(&self) -> Duration {
+        Duration::from_nanos(self.mean_ns)
+    }
+
+    pub fn min(&self) -> Duration {
+        Duration::from_nanos(self.min_ns)
+    }
+
+    pub fn max(&self) -> Duration {
+        Duration::from_nanos(self.max_ns)
+    }
+}
+
+// Parts that were not asked for or are not solved yet are left out
+#[derive(Debug, Clone, Serialize)]
+pub struct SolutionBench {
+    pub iterations: usize,
+    pub parse: Stats,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_a: Option<Stats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_b: Option<Stats>,
+}
+
+// One entry of the JSON summary written by `aoc bench --json`
+#[derive(Debug, Clone, Serialize)]
+pub struct DayBench {
+    pub year: u16,
+    pub day: u8,
+    pub name: &'static str,
+    #[serde(flatten)]
+    pub bench: SolutionBench,
+}
+
+// Times parse and each part separately, so a slow parser doesn't hide in
+// the part timings. Both parts reuse the input from the last parse.
+pub fn bench_solution<S: Solution>(input: &str, parts: &[Part], iterations: usize) -> Result<SolutionBench, ParseError> {
+    let iterations = iterations.max(1);
+
+    let mut parse_samples = Vec::with_capacity(iterations);
+    let mut parsed = None;
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let result = black_box(S::parse(black_box(input)))?;
+        parse_samples.push(start.elapsed());
+        parsed = Some(result);
+    }
+
+    let parsed = parsed.expect("Parsed at least once");
+
+    let timed = |part: Part| parts.contains(&part) && !S::UNSOLVED.contains(&part);
+    let time_part = |part: Part| {
+        let samples = (0..iterations)
+            .map(|_| {
+                let start = Instant::now();
+                match part {
+                    Part::A => drop(black_box(S::part_a(black_box(&parsed)))),
+                    Part::B => drop(black_box(S::part_b(black_box(&parsed)))),
+                }
+                start.elapsed()
+            })
+            .collect::<Vec<Duration>>();
+
+        Stats::from_samples(&samples)
+    };
+
+    Ok(SolutionBench {
+        iterations,
+        parse: Stats::from_samples(&parse_samples),
+        part_a: timed(Part::A).then(|| time_part(Part::A)),
+        part_b: timed(Part::B).then(|| time_part(Part::B)),
+    })
+}
@@ -0,0 +1,151 @@
+use std::{
+    io::BufRead,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::{
+    generate::{Generate, Rng},
+    input::{self, InputFile},
+    render::{Frame, Visualize},
+    stream::Stream,
+    ParseError, Part, Solution,
+};
+
+use d5_hydrothermal_venture::HydrothermalVenture;
+use d1_calorie_counting::CalorieCounting;
+use d2_rockpaper_scissors::RockPaperScissors;
+use d3_rucksack_reorganization::RucksackReorganization;
+use d4_camp_cleanup::CampCleanup;
+use d5_supply_stacks::SupplyStacks;
+use d6_tuning_trouble::TuningTrouble;
+use d7_no_space_left_on_device::NoSpaceLeftOnDevice;
+use d8_treetop_tree_house::TreetopTreeHouse;
+use d9_rope_bridge::RopeBridge;
+use d10_cathode_ray_tube::CathodeRayTube;
+use d11_monkey_in_the_middle::MonkeyInTheMiddle;
+use d12_hill_climbing_algorithm::HillClimbingAlgorithm;
+use d13_distress_signal::DistressSignal;
+use d14_regolith_resovoir::RegolithReservoir;
+use d15_beacon_exclusion_zone::BeaconExclusionZone;
+use d16_proboscidea_volcanium::ProboscideaVolcanium;
+
+#[cfg(feature = "bench")]
+pub mod bench;
+
+#[cfg(feature = "bench")]
+use bench::{bench_solution, SolutionBench};
+
+pub type FramesFn = fn(&str, Part, usize) -> Result<Vec<Frame>, ParseError>;
+pub type StreamFn = fn(Part, &mut dyn BufRead) -> Result<String, ParseError>;
+
+// Every puzzle the runner knows about, in the order they were solved. The
+// runner, the wasm build and anything else that solves puzzles all share
+// this list.
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub name: &'static str,
+    // Crate directory, relative to the workspace
+    pub dir: &'static str,
+    pub solve: fn(Part, &str) -> Result<String, ParseError>,
+    // Parts that `solve` only reports as not solved yet
+    pub unsolved: &'static [Part],
+    #[cfg(feature = "bench")]
+    pub bench: fn(&str, &[Part], usize) -> Result<SolutionBench, ParseError>,
+    // Only for the days that can show their work
+    pub frames: Option<FramesFn>,
+    // Only for the days that can make up inputs, from a size and a seed
+    pub generate: Option<fn(usize, u64) -> String>,
+    // Only for the days that can solve inputs too big to read whole
+    pub stream: Option<StreamFn>,
+}
+
+impl Day {
+    const fn new<S: Solution>(year: u16, day: u8, name: &'static str, dir: &'static str) -> Self {
+        Day {
+            year,
+            day,
+            name,
+            dir,
+            solve: S::solve,
+            unsolved: S::UNSOLVED,
+            #[cfg(feature = "bench")]
+            bench: bench_solution::<S>,
+            frames: None,
+            generate: None,
+            stream: None,
+        }
+    }
+
+    const fn visualized<S: Visualize>(self) -> Self {
+        Day {
+            frames: Some(solution_frames::<S>),
+            ..self
+        }
+    }
+
+    const fn generated<S: Generate>(self) -> Self {
+        Day {
+            generate: Some(solution_generate::<S>),
+            ..self
+        }
+    }
+
+    const fn streamed<S: Stream>(self) -> Self {
+        Day {
+            stream: Some(solution_stream::<S>),
+            ..self
+        }
+    }
+
+    pub fn path(&self) -> PathBuf {
+        workspace_dir().join(self.dir)
+    }
+
+    pub fn inputs(&self) -> Vec<InputFile> {
+        input::list_inputs(self.path())
+    }
+}
+
+fn solution_frames<S: Visualize>(input: &str, part: Part, every: usize) -> Result<Vec<Frame>, ParseError> {
+    let parsed = S::parse(input).map_err(|e| e.with_source(input))?;
+    Ok(S::frames(&parsed, part, every))
+}
+
+fn solution_generate<S: Generate>(size: usize, seed: u64) -> String {
+    S::generate(size, &mut Rng::new(seed))
+}
+
+fn solution_stream<S: Stream>(part: Part, reader: &mut dyn BufRead) -> Result<String, ParseError> {
+    S::solve_stream(part, reader)
+}
+
+pub const DEFAULT_YEAR: u16 = 2022;
+
+pub static DAYS: &[Day] = &[
+    Day::new::<HydrothermalVenture>(2021, 5, "hydrothermal-venture", "old-d5-hydrothermal-venture"),
+    Day::new::<CalorieCounting>(2022, 1, "calorie-counting", "d1-calorie-counting").generated::<CalorieCounting>().streamed::<CalorieCounting>(),
+    Day::new::<RockPaperScissors>(2022, 2, "rockpaper-scissors", "d2-rockpaper-scissors").generated::<RockPaperScissors>().streamed::<RockPaperScissors>(),
+    Day::new::<RucksackReorganization>(2022, 3, "rucksack-reorganization", "d3-rucksack-reorganization").generated::<RucksackReorganization>().streamed::<RucksackReorganization>(),
+    Day::new::<CampCleanup>(2022, 4, "camp-cleanup", "d4-camp-cleanup").streamed::<CampCleanup>(),
+    Day::new::<SupplyStacks>(2022, 5, "supply-stacks", "d5-supply-stacks").generated::<SupplyStacks>(),
+    Day::new::<TuningTrouble>(2022, 6, "tuning-trouble", "d6-tuning-trouble"),
+    Day::new::<NoSpaceLeftOnDevice>(2022, 7, "no-space-left-on-device", "d7-no-space-left-on-device").generated::<NoSpaceLeftOnDevice>(),
+    Day::new::<TreetopTreeHouse>(2022, 8, "treetop-tree-house", "d8-treetop-tree-house").visualized::<TreetopTreeHouse>(),
+    Day::new::<RopeBridge>(2022, 9, "rope-bridge", "d9-rope-bridge").visualized::<RopeBridge>().streamed::<RopeBridge>(),
+    Day::new::<CathodeRayTube>(2022, 10, "cathode-ray-tube", "d10-cathode-ray-tube").visualized::<CathodeRayTube>().streamed::<CathodeRayTube>(),
+    Day::new::<MonkeyInTheMiddle>(2022, 11, "monkey-in-the-middle", "d11-monkey-in-the-middle"),
+    Day::new::<HillClimbingAlgorithm>(2022, 12, "hill-climbing-algorithm", "d12-hill-climbing-algorithm").visualized::<HillClimbingAlgorithm>().generated::<HillClimbingAlgorithm>(),
+    Day::new::<DistressSignal>(2022, 13, "distress-signal", "d13-distress-signal").generated::<DistressSignal>(),
+    Day::new::<RegolithReservoir>(2022, 14, "regolith-resovoir", "d14-regolith-resovoir").visualized::<RegolithReservoir>(),
+    Day::new::<BeaconExclusionZone>(2022, 15, "beacon-exclusion-zone", "d15-beacon-exclusion-zone"),
+    Day::new::<ProboscideaVolcanium>(2022, 16, "proboscidea-volcanium", "d16-proboscidea-volcanium").generated::<ProboscideaVolcanium>(),
+];
+
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+pub fn find_day(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
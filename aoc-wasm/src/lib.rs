@@ -0,0 +1,105 @@
+use aoc_common::{
+    render::{self, Frame},
+    Part,
+};
+use aoc_registry::{Day, DAYS, DEFAULT_YEAR};
+use wasm_bindgen::prelude::*;
+
+// Only the current year is in the browser
+fn find_day(day: u8) -> Result<&'static Day, String> {
+    aoc_registry::find_day(DEFAULT_YEAR, day).ok_or(format!("No solution for day {day}"))
+}
+
+// The exported functions wrap these, which also work outside of wasm
+
+pub fn solve_day(day: u8, part: &str, input: &str) -> Result<String, String> {
+    let part = part.parse::<Part>()?;
+    (find_day(day)?.solve)(part, input).map_err(|e| e.to_string())
+}
+
+pub fn render_day(day: u8, part: &str, input: &str, every: usize) -> Result<Vec<Frame>, String> {
+    let part = part.parse::<Part>()?;
+    let day = find_day(day)?;
+    let frames = day.frames.ok_or(format!("Day {} has nothing to render", day.day))?;
+
+    frames(input, part, every).map_err(|e| e.to_string())
+}
+
+/// Solves `part` ("a" or "b") of a 2022 `day`, throwing on invalid input
+#[wasm_bindgen]
+pub fn solve(day: u8, part: &str, input: &str) -> Result<String, JsError> {
+    solve_day(day, part, input).map_err(|e| JsError::new(&e))
+}
+
+/// Every day as "number name"
+#[wasm_bindgen]
+pub fn days() -> Vec<String> {
+    DAYS.iter()
+        .filter(|d| d.year == DEFAULT_YEAR)
+        .map(|d| format!("{} {}", d.day, d.name))
+        .collect()
+}
+
+/// Whether `render` can draw the day
+#[wasm_bindgen]
+pub fn can_render(day: u8) -> bool {
+    find_day(day).is_ok_and(|d| d.frames.is_some())
+}
+
+/// Frames of a day's simulation, for drawing to a canvas
+#[wasm_bindgen]
+pub struct Animation {
+    frames: Vec<Frame>,
+    rows: usize,
+    cols: usize,
+    scale: usize,
+}
+
+#[wasm_bindgen]
+impl Animation {
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// In pixels, the same for every frame
+    pub fn width(&self) -> u32 {
+        (self.cols * self.scale) as u32
+    }
+
+    pub fn height(&self) -> u32 {
+        (self.rows * self.scale) as u32
+    }
+
+    /// Pixels of frame `index`, for `new ImageData(new Uint8ClampedArray(pixels), width, height)`
+    pub fn pixels(&self, index: usize) -> Vec<u8> {
+        self.frames.get(index).map_or(Vec::new(), |f| {
+            render::pixels(f, self.rows, self.cols, self.scale)
+                .into_iter()
+                .flat_map(|[r, g, b]| [r, g, b, 255])
+                .collect()
+        })
+    }
+
+    /// Frame `index` as plain text
+    pub fn text(&self, index: usize) -> String {
+        self.frames.get(index).map_or(String::new(), Frame::to_string)
+    }
+}
+
+/// Draws `part` of a day, keeping every `every`th step and the last one.
+/// Frames are padded to the largest one.
+#[wasm_bindgen]
+pub fn render(day: u8, part: &str, input: &str, every: usize, scale: usize) -> Result<Animation, JsError> {
+    let frames = render_day(day, part, input, every).map_err(|e| JsError::new(&e))?;
+
+    Ok(Animation {
+        rows: frames.iter().map(Frame::rows).max().unwrap_or(0),
+        cols: frames.iter().map(Frame::cols).max().unwrap_or(0),
+        scale: scale.max(1),
+        frames,
+    })
+}
@@ -0,0 +1,55 @@
+// Run headlessly with `wasm-pack test --node aoc-wasm`, and as plain tests
+// everywhere else
+use aoc_wasm::{can_render, days, render, solve};
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const CRT_PROGRAM: &str = include_str!("../../d10-cathode-ray-tube/src/test-input2.txt");
+const CAVE: &str = include_str!("../../d14-regolith-resovoir/src/test-input.txt");
+const ROPE: &str = include_str!("../../d9-rope-bridge/test-input.txt");
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn test_solve() {
+    assert_eq!(solve(10, "a", CRT_PROGRAM).unwrap(), "13140");
+    assert_eq!(solve(14, "b", CAVE).unwrap(), "93");
+    assert_eq!(solve(9, "A", ROPE).unwrap(), "13");
+    assert!(solve(10, "b", CRT_PROGRAM).unwrap().starts_with("##..##..##..##..##..##..##..##..##..##.."));
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn test_days() {
+    assert_eq!(days().len(), 16);
+    assert_eq!(days()[0], "1 calorie-counting");
+    assert!([9, 10, 14].into_iter().all(can_render));
+    assert!(!can_render(1));
+    assert!(!can_render(17));
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn test_render() {
+    let crt = render(10, "b", CRT_PROGRAM, 40, 2).unwrap();
+    assert_eq!((crt.width(), crt.height()), (80, 12));
+    assert_eq!(crt.pixels(0).len(), 80 * 12 * 4);
+    assert_eq!(crt.text(crt.len() - 1).lines().count(), 6);
+
+    let cave = render(14, "a", CAVE, 1, 1).unwrap();
+    assert_eq!(cave.len(), 25);
+    assert!(cave.text(24).contains('o'));
+
+    let rope = render(9, "b", ROPE, 1, 3).unwrap();
+    assert!(!rope.is_empty());
+    assert_eq!(rope.pixels(0).len() as u32, rope.width() * rope.height() * 4);
+}
+
+// Errors become exceptions in JavaScript, which only exist in wasm
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen_test]
+fn test_errors() {
+    assert!(solve(10, "c", CRT_PROGRAM).is_err());
+    assert!(solve(17, "a", CRT_PROGRAM).is_err());
+    assert!(solve(10, "a", "jump 3").is_err());
+    assert!(render(1, "a", "1000", 1, 1).is_err());
+}
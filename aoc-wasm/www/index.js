@@ -0,0 +1,70 @@
+import init, { can_render, days, render, solve } from "../pkg/aoc_wasm.js";
+
+const day = document.getElementById("day");
+const part = document.getElementById("part");
+const input = document.getElementById("input");
+const every = document.getElementById("every");
+const answer = document.getElementById("answer");
+const canvas = document.getElementById("canvas");
+const solveButton = document.getElementById("solve");
+const renderButton = document.getElementById("render");
+
+let playing = null;
+
+function show(text, isError = false) {
+    answer.textContent = text;
+    answer.className = isError ? "error" : "";
+}
+
+function stop() {
+    if (playing !== null) {
+        cancelAnimationFrame(playing);
+        playing = null;
+    }
+}
+
+// Draws a frame per animation frame, then leaves the last one up
+function play(animation) {
+    stop();
+    canvas.width = animation.width();
+    canvas.height = animation.height();
+    const context = canvas.getContext("2d");
+    let index = 0;
+
+    const step = () => {
+        const pixels = new Uint8ClampedArray(animation.pixels(index));
+        context.putImageData(new ImageData(pixels, animation.width(), animation.height()), 0, 0);
+        index += 1;
+        playing = index < animation.len() ? requestAnimationFrame(step) : null;
+    };
+    step();
+}
+
+await init();
+
+for (const entry of days()) {
+    const [number] = entry.split(" ");
+    day.add(new Option(entry, number));
+}
+
+day.addEventListener("change", () => {
+    renderButton.disabled = !can_render(Number(day.value));
+});
+
+solveButton.addEventListener("click", () => {
+    try {
+        show(solve(Number(day.value), part.value, input.value));
+    } catch (e) {
+        show(e.message, true);
+    }
+});
+
+renderButton.addEventListener("click", () => {
+    try {
+        const animation = render(Number(day.value), part.value, input.value, Number(every.value), 4);
+        show(`${animation.len()} frames`);
+        play(animation);
+    } catch (e) {
+        show(e.message, true);
+    }
+});
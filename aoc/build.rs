@@ -0,0 +1,52 @@
+// Generates one test per input listed in each workspace member's answers.toml,
+// see tests/answers.rs
+use std::{env, fs, path::Path};
+
+fn main() {
+    let workspace = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("..");
+    let manifest = workspace.join("Cargo.toml");
+    println!("cargo:rerun-if-changed={}", manifest.display());
+
+    let manifest: toml::Table = fs::read_to_string(&manifest).unwrap().parse().unwrap();
+    let members = manifest["workspace"]["members"].as_array().unwrap();
+
+    let mut tests = String::new();
+
+    for member in members.iter().filter_map(|m| m.as_str()) {
+        let path = workspace.join(member).join("answers.toml");
+        println!("cargo:rerun-if-changed={}", path.display());
+
+        let Ok(contents) = fs::read_to_string(&path) else {
+            continue;
+        };
+
+        let answers: toml::Table = contents
+            .parse()
+            .unwrap_or_else(|e| panic!("Invalid {}: {e}", path.display()));
+        let year = answers["year"].as_integer().unwrap();
+        let day = answers["day"].as_integer().unwrap();
+
+        let Some(inputs) = answers.get("input").and_then(|i| i.as_array()) else {
+            continue;
+        };
+
+        for input in inputs {
+            let file = input["file"].as_str().unwrap();
+            let name = file
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+                .collect::<String>();
+
+            if let Some(reason) = input.get("ignore").and_then(|r| r.as_str()) {
+                tests.push_str(&format!("#[ignore = {reason:?}]\n"));
+            }
+
+            tests.push_str(&format!(
+                "#[test]\nfn y{year}_d{day:02}_{name}() {{\n    check({year}, {day}, {file:?});\n}}\n\n"
+            ));
+        }
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("answer_tests.rs");
+    fs::write(out, tests).unwrap();
+}
@@ -0,0 +1,107 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    fs::read_to_string,
+    path::Path,
+};
+
+use aoc_common::{input, Part};
+use serde::Deserialize;
+
+use crate::registry::Day;
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+// The answers.toml in a day's crate directory
+#[derive(Debug, Deserialize)]
+pub struct Answers {
+    pub year: u16,
+    pub day: u8,
+    #[serde(default, rename = "input")]
+    pub inputs: Vec<ExpectedAnswers>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExpectedAnswers {
+    // Relative to the crate directory
+    pub file: String,
+    pub a: Option<String>,
+    pub b: Option<String>,
+    // Skips the generated test. `aoc verify` still checks it, but doesn't
+    // fail on it.
+    pub ignore: Option<String>,
+}
+
+impl ExpectedAnswers {
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::A => self.a.as_deref(),
+            Part::B => self.b.as_deref(),
+        }
+    }
+}
+
+// None when the day has no answers file yet
+pub fn read_answers(dir: &Path) -> Result<Option<Answers>, Box<dyn Error>> {
+    let path = dir.join(ANSWERS_FILE);
+
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let contents = read_to_string(&path).map_err(|e| format!("Could not read {}: {e}", path.display()))?;
+    let answers = toml::from_str(&contents).map_err(|e| format!("Invalid {}: {e}", path.display()))?;
+
+    Ok(Some(answers))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing,
+    Error(String),
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => "pass".fmt(f),
+            Status::Fail { .. } => "FAIL".fmt(f),
+            Status::Missing => "missing".fmt(f),
+            Status::Error(_) => "ERROR".fmt(f),
+        }
+    }
+}
+
+// Only runs the parts with an expected answer, so unsolved parts are
+// reported as missing instead of being run.
+// Trailing whitespace is ignored, multi-line answers end with a newline in TOML.
+pub fn verify_input(day: &Day, expected: &ExpectedAnswers) -> Vec<(Part, Status)> {
+    let input = input::read(day.path(), &expected.file);
+
+    [Part::A, Part::B]
+        .into_iter()
+        .map(|part| {
+            let Some(answer) = expected.answer(part) else {
+                return (part, Status::Missing);
+            };
+
+            let input = match &input {
+                Ok(input) => input,
+                Err(e) => return (part, Status::Error(format!("Could not read {}: {e}", expected.file))),
+            };
+
+            let status = match (day.solve)(part, input) {
+                Ok(actual) if actual.trim_end() == answer.trim_end() => Status::Pass,
+                Ok(actual) => Status::Fail {
+                    expected: answer.trim_end().to_string(),
+                    actual: actual.trim_end().to_string(),
+                },
+                Err(e) => Status::Error(e.with_file(expected.file.clone()).to_string()),
+            };
+
+            (part, status)
+        })
+        .collect()
+}
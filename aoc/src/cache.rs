@@ -0,0 +1,113 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    fs,
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::fetch::{FetchError, Fetcher, SESSION_VAR};
+
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+#[derive(Debug)]
+pub enum CacheError {
+    // Not cached, and there was nothing to fetch it with
+    Miss { year: u16, day: u8, path: PathBuf },
+    Fetch { year: u16, day: u8, path: PathBuf, error: FetchError },
+    Io(io::Error),
+}
+
+impl Display for CacheError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CacheError::Miss { year, day, path } => write!(
+                f,
+                "No cached input for {year} day {day} at {}, and fetching is disabled. \
+                 Set {SESSION_VAR} to your session cookie to download it, or save it there by hand.",
+                path.display()
+            ),
+            CacheError::Fetch { year, day, path, error } => write!(
+                f,
+                "No cached input for {year} day {day} at {}, and fetching it failed: {error}",
+                path.display()
+            ),
+            CacheError::Io(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for CacheError {}
+
+impl From<io::Error> for CacheError {
+    fn from(e: io::Error) -> Self {
+        CacheError::Io(e)
+    }
+}
+
+// Puzzle inputs stored as <dir>/<year>/<day>.txt, so each one is only
+// downloaded once per user
+pub struct InputCache {
+    dir: PathBuf,
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        InputCache { dir: dir.into() }
+    }
+
+    // AOC_CACHE_DIR, or aoc/ in the user's cache directory
+    pub fn from_env() -> Option<Self> {
+        let dir = match std::env::var_os(CACHE_DIR_VAR) {
+            Some(dir) => PathBuf::from(dir),
+            None => dirs::cache_dir()?.join("aoc"),
+        };
+
+        Some(InputCache::new(dir))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir.join(year.to_string()).join(format!("{day}.txt"))
+    }
+
+    // Answers submitted for the day, see `submit::History`
+    pub fn history_path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir.join(year.to_string()).join(format!("{day}-attempts.json"))
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<String> {
+        fs::read_to_string(self.path(year, day)).ok()
+    }
+
+    pub fn store(&self, year: u16, day: u8, input: &str) -> io::Result<()> {
+        let path = self.path(year, day);
+        fs::create_dir_all(path.parent().expect("Cached inputs are inside a year directory"))?;
+        fs::write(path, input)
+    }
+
+    // Fetches and stores the input on a cache miss. Without a fetcher the
+    // cache is used offline.
+    pub fn load(&self, year: u16, day: u8, fetcher: Option<&dyn Fetcher>) -> Result<String, CacheError> {
+        if let Some(input) = self.get(year, day) {
+            return Ok(input);
+        }
+
+        let path = self.path(year, day);
+
+        let Some(fetcher) = fetcher else {
+            return Err(CacheError::Miss { year, day, path });
+        };
+
+        let input = fetcher
+            .fetch_input(year, day)
+            .map_err(|error| CacheError::Fetch { year, day, path, error })?;
+
+        self.store(year, day, &input)?;
+
+        Ok(input)
+    }
+}
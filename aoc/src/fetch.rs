@@ -0,0 +1,160 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    fs,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(Debug)]
+pub enum FetchError {
+    // The server could not be reached at all
+    Offline(String),
+    Http { status: u16, message: String },
+    Io(std::io::Error),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::Offline(e) => write!(f, "Could not reach the server, are you offline? {e}"),
+            FetchError::Http { status: 400 | 401, message } => {
+                write!(f, "The session cookie was rejected, check {SESSION_VAR}: {message}")
+            }
+            FetchError::Http { status, message } => write!(f, "The server answered {status}: {message}"),
+            FetchError::Io(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for FetchError {}
+
+impl From<std::io::Error> for FetchError {
+    fn from(e: std::io::Error) -> Self {
+        FetchError::Io(e)
+    }
+}
+
+// Where puzzle inputs come from when they aren't cached yet
+pub trait Fetcher {
+    fn fetch_input(&self, year: u16, day: u8) -> Result<String, FetchError>;
+}
+
+// Keeps requests at least `interval` apart, even across separate runs, by
+// remembering when the last request was made in `state_file`
+pub struct RateLimiter {
+    interval: Duration,
+    state_file: Option<PathBuf>,
+}
+
+impl RateLimiter {
+    pub fn new(interval: Duration) -> Self {
+        RateLimiter {
+            interval,
+            state_file: None,
+        }
+    }
+
+    pub fn with_state_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.state_file = Some(path.into());
+        self
+    }
+
+    fn last_request(&self) -> Option<SystemTime> {
+        let millis = fs::read_to_string(self.state_file.as_ref()?).ok()?.trim().parse().ok()?;
+        Some(UNIX_EPOCH + Duration::from_millis(millis))
+    }
+
+    // Sleeps until the next request is allowed, then records it
+    pub fn wait(&self) -> Result<(), FetchError> {
+        if let Some(last) = self.last_request() {
+            let elapsed = SystemTime::now().duration_since(last).unwrap_or_default();
+            if let Some(remaining) = self.interval.checked_sub(elapsed) {
+                thread::sleep(remaining);
+            }
+        }
+
+        if let Some(path) = &self.state_file {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+            fs::write(path, now.as_millis().to_string())?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+    limiter: RateLimiter,
+    agent: ureq::Agent,
+}
+
+impl HttpFetcher {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>, limiter: RateLimiter) -> Self {
+        HttpFetcher {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+            limiter,
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(concat!("aoc-runner/", env!("CARGO_PKG_VERSION")))
+                .build(),
+        }
+    }
+
+    // Configured from AOC_SESSION and AOC_BASE_URL, None without a session
+    pub fn from_env(limiter: RateLimiter) -> Option<Self> {
+        let session = std::env::var(SESSION_VAR).ok().filter(|s| !s.trim().is_empty())?;
+        let base_url = std::env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        Some(HttpFetcher::new(base_url, session.trim(), limiter))
+    }
+
+    pub(crate) fn get(&self, path: &str) -> Result<String, FetchError> {
+        self.limiter.wait()?;
+
+        self.agent
+            .get(&format!("{}{path}", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(to_fetch_error)?
+            .into_string()
+            .map_err(FetchError::Io)
+    }
+
+    pub(crate) fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String, FetchError> {
+        self.limiter.wait()?;
+
+        self.agent
+            .post(&format!("{}{path}", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(form)
+            .map_err(to_fetch_error)?
+            .into_string()
+            .map_err(FetchError::Io)
+    }
+}
+
+fn to_fetch_error(e: ureq::Error) -> FetchError {
+    match e {
+        ureq::Error::Status(status, response) => FetchError::Http {
+            status,
+            message: response.into_string().unwrap_or_default().trim().to_string(),
+        },
+        ureq::Error::Transport(e) => FetchError::Offline(e.to_string()),
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch_input(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        self.get(&format!("/{year}/day/{day}/input"))
+    }
+}
@@ -0,0 +1,9 @@
+pub mod answers;
+pub mod cache;
+pub mod fetch;
+pub mod scaffold;
+pub mod submit;
+
+// Shared with the other front ends, re-exported under the names they had
+// when they lived here
+pub use aoc_registry::{self as registry, bench};
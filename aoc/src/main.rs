@@ -0,0 +1,726 @@
+use std::{
+    error::Error,
+    fs::{read_to_string, write, File},
+    io::{self, BufRead, BufReader, Cursor},
+    path::{Path, PathBuf},
+    process, thread,
+    time::Duration,
+};
+
+use aoc::{
+    answers::{read_answers, verify_input, Status, ANSWERS_FILE},
+    bench::{DayBench, Stats},
+    cache::InputCache,
+    fetch::{Fetcher, HttpFetcher, RateLimiter, SESSION_VAR},
+    registry::{find_day, workspace_dir, Day, DAYS, DEFAULT_YEAR},
+    scaffold::Scaffold,
+    submit::{History, Verdict},
+};
+use aoc_common::{
+    input::{self, InputKind},
+    render::{self, Frame},
+    stream::LineParser,
+    Part,
+};
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
+use d1_calorie_counting::{
+    inventory::{read_inventory, Report},
+    top_elves, total_calories,
+};
+use d2_rockpaper_scissors::{
+    cipher::{highest, lowest, score_ciphers, Tally},
+    parse_moveset,
+};
+use tracing_subscriber::EnvFilter;
+
+#[derive(Parser)]
+#[command(about = "Runs any Advent of Code solution in the workspace")]
+struct Cli {
+    /// Only use inputs that are already cached, never download them
+    #[arg(long, global = true)]
+    offline: bool,
+    /// Log what the solutions are doing to stderr, -v for debug and -vv for
+    /// every step. RUST_LOG takes precedence and can pick days by crate name,
+    /// as in RUST_LOG=d14_regolith_resovoir=trace
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day's puzzle input
+    Run {
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+        /// Part to run (a or b), runs both when omitted
+        #[arg(long)]
+        part: Option<Part>,
+        /// Input file, `-` for stdin. Relative paths that don't exist are looked
+        /// up in the day's crate. Defaults to the day's only real input, or the
+        /// cached one when the crate has none.
+        #[arg(long)]
+        input: Option<String>,
+        /// Solve a line at a time without reading the input whole, for the
+        /// days that can
+        #[arg(long)]
+        stream: bool,
+    },
+    /// Time parsing and solving a day's puzzle input
+    Bench {
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+        /// Part to time (a or b), times both when omitted
+        #[arg(long)]
+        part: Option<Part>,
+        /// Input file, `-` for stdin. Relative paths that don't exist are looked
+        /// up in the day's crate. Defaults to the day's only real input, or the
+        /// cached one when the crate has none.
+        #[arg(long)]
+        input: Option<String>,
+        #[arg(long, default_value_t = 10)]
+        iterations: usize,
+        /// Also write the timings as JSON to this file
+        #[arg(long)]
+        json: Option<PathBuf>,
+    },
+    /// Draw a day's simulation as text or images
+    Render {
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+        #[arg(long)]
+        part: Part,
+        /// Input file, as for `run`
+        #[arg(long)]
+        input: Option<String>,
+        #[arg(long, value_enum, default_value_t = Format::Ascii)]
+        format: Format,
+        /// Only keep every Nth step of the simulation
+        #[arg(long, default_value_t = 1)]
+        every: usize,
+        /// File to write png and gif output to. Several png frames are
+        /// numbered, as in name-0001.png
+        #[arg(long)]
+        output: Option<PathBuf>,
+        /// Pixels per cell in images
+        #[arg(long, default_value_t = 4)]
+        scale: usize,
+        /// Milliseconds between frames of ansi and gif output
+        #[arg(long, default_value_t = 100)]
+        delay: u16,
+    },
+    /// Make up a random puzzle input, the same seed gives the same input
+    Gen {
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+        /// Roughly how many lines or entries, what counts depends on the day
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// File to write the input to instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Check solutions against the answers.toml of every day
+    Verify {
+        /// Only check this year
+        #[arg(long)]
+        year: Option<u16>,
+        /// Only check this day
+        #[arg(long)]
+        day: Option<u8>,
+    },
+    /// List the example and real inputs of every day
+    Inputs {
+        /// Only list this year
+        #[arg(long)]
+        year: Option<u16>,
+        /// Only list this day
+        #[arg(long)]
+        day: Option<u8>,
+    },
+    /// Download a day's puzzle input into the input cache
+    Fetch {
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+        /// Download again even if the input is already cached
+        #[arg(long)]
+        force: bool,
+    },
+    /// Submit an answer, unless earlier attempts show it can't be right
+    Submit {
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+        #[arg(long)]
+        part: Part,
+        /// Answer to submit, defaults to solving the day's input
+        #[arg(long)]
+        answer: Option<String>,
+        /// Input file to solve when no answer is given, as for `run`
+        #[arg(long)]
+        input: Option<String>,
+    },
+    /// List the answers submitted for a day and what they were
+    Attempts {
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+    },
+    /// Score the strategy guide of 2022 day 2 under every way its letters
+    /// could be read, for guides whose cipher is unknown
+    Ciphers {
+        /// Input file, as for `run`
+        #[arg(long)]
+        input: Option<String>,
+    },
+    /// Rank the elves of 2022 day 1 by the calories they carry, reading the
+    /// input a line at a time
+    Elves {
+        /// How many of the best elves to list
+        #[arg(long, default_value_t = 3)]
+        top: usize,
+        /// Input file, as for `run`
+        #[arg(long)]
+        input: Option<String>,
+        /// List every elf's snacks and statistics, and how the totals are
+        /// spread, instead of the best elves
+        #[arg(long, value_enum)]
+        report: Option<ReportFormat>,
+        /// Buckets in the report's histogram of totals
+        #[arg(long, default_value_t = 10)]
+        buckets: usize,
+        /// File to write the report to instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// List every available day
+    List,
+    /// Start a new day's crate and add it to the workspace and the registry
+    New {
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+        /// Puzzle title as a slug, the crate is named dN-slug
+        #[arg(long)]
+        name: String,
+        /// File with the puzzle's example, copied to src/test-input.txt
+        #[arg(long)]
+        example: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// Plain text, frames separated by blank lines
+    Ascii,
+    /// Coloured text, animated in place
+    Ansi,
+    /// One image per frame
+    Png,
+    /// A single animated image
+    Gif,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ReportFormat {
+    Text,
+    /// One row per elf
+    Csv,
+    Json,
+}
+
+// Advent of Code asks for automated requests to be throttled
+const REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+
+fn input_cache() -> Result<InputCache, Box<dyn Error>> {
+    Ok(InputCache::from_env().ok_or("Could not find a cache directory, set AOC_CACHE_DIR")?)
+}
+
+// Shares the time of the last request between runs through the cache
+fn fetcher(cache: &InputCache, offline: bool) -> Option<HttpFetcher> {
+    if offline {
+        return None;
+    }
+
+    HttpFetcher::from_env(RateLimiter::new(REQUEST_INTERVAL).with_state_file(cache.dir().join("last-request")))
+}
+
+// Returns the input's name for error messages along with its contents
+fn load_input(day: &Day, input: Option<&str>, offline: bool) -> Result<(String, String), Box<dyn Error>> {
+    let path = match input {
+        Some("-") => return Ok(("<stdin>".to_string(), input::read_stdin()?)),
+        Some(input) if Path::new(input).exists() => PathBuf::from(input),
+        Some(input) => input::resolve(day.path(), input),
+        None => {
+            let real = day.inputs().into_iter().filter(|i| i.kind == InputKind::Real).collect::<Vec<_>>();
+            match &real[..] {
+                [input] => input.path.clone(),
+                [] => {
+                    let cache = input_cache()?;
+                    let fetcher = fetcher(&cache, offline);
+                    let contents = cache.load(day.year, day.day, fetcher.as_ref().map(|f| f as &dyn Fetcher))?;
+                    return Ok((cache.path(day.year, day.day).display().to_string(), contents));
+                }
+                _ => return Err(format!("{} day {} has several real inputs, pass --input", day.year, day.day).into()),
+            }
+        }
+    };
+
+    let file = path.display().to_string();
+    let contents = read_to_string(&path).map_err(|e| format!("Could not read {file}: {e}"))?;
+
+    Ok((file, contents))
+}
+
+// Like load_input, but only opens files. The default inputs are small, so
+// they are still read whole.
+fn open_input(day: &Day, input: Option<&str>, offline: bool) -> Result<(String, Box<dyn BufRead>), Box<dyn Error>> {
+    let path = match input {
+        Some("-") => return Ok(("<stdin>".to_string(), Box::new(io::stdin().lock()))),
+        Some(input) if Path::new(input).exists() => PathBuf::from(input),
+        Some(input) => input::resolve(day.path(), input),
+        None => {
+            let (file, contents) = load_input(day, None, offline)?;
+            return Ok((file, Box::new(Cursor::new(contents))));
+        }
+    };
+
+    let file = path.display().to_string();
+    let reader = File::open(&path).map_err(|e| format!("Could not read {file}: {e}"))?;
+
+    Ok((file, Box::new(BufReader::new(reader))))
+}
+
+fn print_stats(label: &str, stats: &Stats) {
+    println!(
+        "  {label:<6} mean {:>12?}  min {:>12?}  max {:>12?}",
+        stats.mean(),
+        stats.min(),
+        stats.max()
+    );
+}
+
+fn write_frames(frames: &[Frame], format: Format, output: Option<&Path>, scale: usize, delay: u16) -> Result<(), Box<dyn Error>> {
+    match format {
+        Format::Ascii => {
+            let frames = frames.iter().map(Frame::to_string).collect::<Vec<String>>();
+            println!("{}", frames.join("\n\n"));
+        }
+        Format::Ansi => {
+            for (i, frame) in frames.iter().enumerate() {
+                if i != 0 {
+                    thread::sleep(Duration::from_millis(delay.into()));
+                }
+                // Clears the screen and draws from the top left
+                println!("\x1b[2J\x1b[H{}", render::to_ansi(frame));
+            }
+        }
+        Format::Png => {
+            let output = output.ok_or("Pass --output to write images")?;
+            match frames {
+                [frame] => render::write_png(frame, output, scale)?,
+                frames => {
+                    let stem = output.file_stem().unwrap_or_default().to_string_lossy();
+                    for (i, frame) in frames.iter().enumerate() {
+                        render::write_png(frame, &output.with_file_name(format!("{stem}-{:04}.png", i + 1)), scale)?;
+                    }
+                }
+            }
+        }
+        Format::Gif => {
+            let output = output.ok_or("Pass --output to write images")?;
+            render::write_gif(frames, output, scale, delay)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn print_bounds(history: &History, part: Part) {
+    match history.bounds(part) {
+        (Some(low), Some(high)) => println!("  part {part} is between {low} and {high}"),
+        (Some(low), None) => println!("  part {part} is above {low}"),
+        (None, Some(high)) => println!("  part {part} is below {high}"),
+        (None, None) => {}
+    }
+}
+
+fn run(cli: Cli) -> Result<(), Box<dyn Error>> {
+    match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            stream,
+        } => {
+            let day = find_day(year, day).ok_or(format!("No solution for {year} day {day}"))?;
+
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![Part::A, Part::B],
+            };
+
+            if stream {
+                let stream = day.stream.ok_or(format!("{year} day {} can't be streamed", day.day))?;
+                if input.as_deref() == Some("-") && parts.len() > 1 {
+                    return Err("Pass --part to stream stdin, it can only be read once".into());
+                }
+
+                // Each part reads the input again
+                for part in parts {
+                    let (file, mut reader) = open_input(day, input.as_deref(), cli.offline)?;
+                    println!("{}", stream(part, &mut reader).map_err(|e| e.with_file(file))?);
+                }
+                return Ok(());
+            }
+
+            let (file, input) = load_input(day, input.as_deref(), cli.offline)?;
+            for part in parts {
+                println!("{}", (day.solve)(part, &input).map_err(|e| e.with_file(file.clone()))?);
+            }
+        }
+        Command::Bench {
+            year,
+            day,
+            part,
+            input,
+            iterations,
+            json,
+        } => {
+            let day = find_day(year, day).ok_or(format!("No solution for {year} day {day}"))?;
+            let (file, input) = load_input(day, input.as_deref(), cli.offline)?;
+
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![Part::A, Part::B],
+            };
+
+            let bench = (day.bench)(&input, &parts, iterations).map_err(|e| e.with_file(file.clone()))?;
+
+            println!("{} day {:>2}: {} ({} iterations)", day.year, day.day, day.name, bench.iterations);
+            print_stats("parse", &bench.parse);
+            if let Some(stats) = &bench.part_a {
+                print_stats("part a", stats);
+            }
+            if let Some(stats) = &bench.part_b {
+                print_stats("part b", stats);
+            }
+
+            if let Some(json) = json {
+                let summary = DayBench {
+                    year: day.year,
+                    day: day.day,
+                    name: day.name,
+                    bench,
+                };
+                write(&json, serde_json::to_string_pretty(&summary)?)
+                    .map_err(|e| format!("Could not write {}: {e}", json.display()))?;
+            }
+        }
+        Command::Render {
+            year,
+            day,
+            part,
+            input,
+            format,
+            every,
+            output,
+            scale,
+            delay,
+        } => {
+            let day = find_day(year, day).ok_or(format!("No solution for {year} day {day}"))?;
+            let frames = day.frames.ok_or(format!("{year} day {} has nothing to render", day.day))?;
+            let (file, input) = load_input(day, input.as_deref(), cli.offline)?;
+
+            let frames = frames(&input, part, every).map_err(|e| e.with_file(file))?;
+            if frames.is_empty() {
+                return Err(format!("{year} day {} part {part} has nothing to render", day.day).into());
+            }
+
+            write_frames(&frames, format, output.as_deref(), scale, delay)?;
+        }
+        Command::Gen {
+            year,
+            day,
+            size,
+            seed,
+            output,
+        } => {
+            let day = find_day(year, day).ok_or(format!("No solution for {year} day {day}"))?;
+            let generate = day.generate.ok_or(format!("{year} day {} has no input generator", day.day))?;
+
+            let input = generate(size, seed);
+            match output {
+                Some(output) => write(&output, input + "\n").map_err(|e| format!("Could not write {}: {e}", output.display()))?,
+                None => println!("{input}"),
+            }
+        }
+        Command::Verify { year, day } => {
+            let days = DAYS
+                .iter()
+                .filter(|d| year.is_none_or(|y| d.year == y) && day.is_none_or(|n| d.day == n));
+
+            let mut rows = Vec::new();
+
+            for day in days {
+                let Some(answers) = read_answers(&day.path())? else {
+                    rows.push((day, ANSWERS_FILE.to_string(), None, Status::Missing, None));
+                    continue;
+                };
+
+                for expected in &answers.inputs {
+                    for (part, status) in verify_input(day, expected) {
+                        rows.push((day, expected.file.clone(), Some(part), status, expected.ignore.clone()));
+                    }
+                }
+            }
+
+            let width = rows.iter().map(|(_, file, _, _, _)| file.len()).max().unwrap_or(0);
+
+            println!("{:<4} {:>3}  {:<width$}  {:<4}  Status", "Year", "Day", "Input", "Part");
+            for (day, file, part, status, _) in &rows {
+                let part = part.map_or("-".to_string(), |p| p.to_string());
+                println!("{:<4} {:>3}  {file:<width$}  {part:<4}  {status}", day.year, day.day);
+            }
+
+            // Failures of ignored inputs are already known, so they don't fail the run
+            let count = |f: fn(&Status, bool) -> bool| rows.iter().filter(|(_, _, _, s, ignore)| f(s, ignore.is_some())).count();
+            let passed = count(|s, _| matches!(s, Status::Pass));
+            let failed = count(|s, ignored| matches!(s, Status::Fail { .. } | Status::Error(_)) && !ignored);
+            let known = count(|s, ignored| matches!(s, Status::Fail { .. } | Status::Error(_)) && ignored);
+            let missing = count(|s, _| matches!(s, Status::Missing));
+
+            println!();
+            println!("{passed} passed, {failed} failed, {known} known failures, {missing} missing");
+
+            // Multi-line answers and errors keep to the column of their first line
+            let aligned = |name: &str, value: &str| {
+                for (i, line) in value.lines().enumerate() {
+                    println!("  {:<8}  {line}", if i == 0 { name } else { "" });
+                }
+            };
+            for (day, file, part, status, ignore) in &rows {
+                let part = part.map_or("-".to_string(), |p| p.to_string());
+                let label = match ignore {
+                    Some(reason) => format!("{} day {:>2} {file} part {part}, ignored: {reason}", day.year, day.day),
+                    None => format!("{} day {:>2} {file} part {part}", day.year, day.day),
+                };
+
+                match status {
+                    Status::Fail { expected, actual } => {
+                        println!();
+                        println!("{label}");
+                        aligned("Expected", expected);
+                        aligned("Actual", actual);
+                    }
+                    Status::Error(e) => {
+                        println!();
+                        println!("{label}");
+                        aligned("Error", e);
+                    }
+                    Status::Pass | Status::Missing => {}
+                }
+            }
+
+            if failed != 0 {
+                return Err(format!("{failed} answers did not match").into());
+            }
+        }
+        Command::Inputs { year, day } => {
+            let days = DAYS
+                .iter()
+                .filter(|d| year.is_none_or(|y| d.year == y) && day.is_none_or(|n| d.day == n));
+
+            for day in days {
+                println!("{} day {:>2}: {}", day.year, day.day, day.name);
+                for input in day.inputs() {
+                    let kind = match input.kind {
+                        InputKind::Example => "example",
+                        InputKind::Real => "real",
+                    };
+                    println!("  {kind:<7}  {}", input.name);
+                }
+            }
+        }
+        Command::Fetch { year, day, force } => {
+            let cache = input_cache()?;
+            let path = cache.path(year, day);
+
+            if !force && cache.get(year, day).is_some() {
+                println!("Already cached at {}", path.display());
+                return Ok(());
+            }
+
+            if cli.offline {
+                return Err("Can't fetch inputs with --offline".into());
+            }
+
+            let fetcher = fetcher(&cache, false).ok_or(format!("Set {SESSION_VAR} to your session cookie to fetch inputs"))?;
+            cache.store(year, day, &fetcher.fetch_input(year, day)?)?;
+
+            println!("Saved {year} day {day} to {}", path.display());
+        }
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+            input,
+        } => {
+            let answer = match answer {
+                Some(answer) => answer,
+                None => {
+                    let day = find_day(year, day).ok_or(format!("No solution for {year} day {day}"))?;
+                    let (file, input) = load_input(day, input.as_deref(), cli.offline)?;
+                    (day.solve)(part, &input).map_err(|e| e.with_file(file))?
+                }
+            };
+
+            if cli.offline {
+                return Err("Can't submit answers with --offline".into());
+            }
+
+            let cache = input_cache()?;
+            let path = cache.history_path(year, day);
+            let mut history = History::load(&path)?;
+
+            let fetcher = fetcher(&cache, false).ok_or(format!("Set {SESSION_VAR} to your session cookie to submit answers"))?;
+            let verdict = history.submit(&fetcher, year, day, part, &answer);
+            history.save(&path).map_err(|e| format!("Could not write {}: {e}", path.display()))?;
+
+            match verdict? {
+                Verdict::Correct => println!("{} is correct", answer.trim()),
+                verdict => {
+                    println!("{} is {verdict}", answer.trim());
+                    print_bounds(&history, part);
+                }
+            }
+        }
+        Command::Attempts { year, day } => {
+            let history = History::load(&input_cache()?.history_path(year, day))?;
+
+            for part in [Part::A, Part::B] {
+                println!("Part {part}:");
+                for attempt in history.attempts(part) {
+                    println!("  {:<20} {}", attempt.answer, attempt.verdict);
+                }
+                print_bounds(&history, part);
+            }
+        }
+        Command::Ciphers { input } => {
+            let day = find_day(2022, 2).ok_or("No solution for 2022 day 2")?;
+            let (file, reader) = open_input(day, input.as_deref(), cli.offline)?;
+
+            let tally = LineParser::new(reader.lines(), parse_moveset)
+                .collect::<Result<Tally, _>>()
+                .map_err(|e| e.with_file(file))?;
+            let scores = score_ciphers(&tally);
+
+            for (cipher, score) in &scores {
+                println!("{:<52} {score:>10}", cipher.to_string());
+            }
+            if let (Some((best, high)), Some((worst, low))) = (highest(&scores), lowest(&scores)) {
+                println!();
+                println!("Highest {high:>10}  {best}");
+                println!("Lowest  {low:>10}  {worst}");
+            }
+        }
+        Command::Elves {
+            top,
+            input,
+            report,
+            buckets,
+            output,
+        } => {
+            let day = find_day(2022, 1).ok_or("No solution for 2022 day 1")?;
+            let (file, reader) = open_input(day, input.as_deref(), cli.offline)?;
+
+            if let Some(format) = report {
+                let elves = read_inventory(reader).map_err(|e| e.with_file(file))?;
+                let report = Report::new(&elves, buckets);
+                let report = match format {
+                    ReportFormat::Text => report.to_string(),
+                    ReportFormat::Csv => report.to_csv(),
+                    ReportFormat::Json => serde_json::to_string_pretty(&report)? + "\n",
+                };
+
+                match output {
+                    Some(output) => write(&output, report).map_err(|e| format!("Could not write {}: {e}", output.display()))?,
+                    None => print!("{report}"),
+                }
+                return Ok(());
+            }
+            let ranked = top_elves(reader, top).map_err(|e| e.with_file(file))?;
+
+            // Elves are numbered from 1, as in the puzzle
+            for (rank, (elf, calories)) in ranked.iter().enumerate() {
+                println!("{:>4}. elf {:<8} {calories}", rank + 1, elf + 1);
+            }
+            println!("Total {}", total_calories(&ranked));
+        }
+        Command::List => {
+            for day in DAYS {
+                println!("{} day {:>2}: {}", day.year, day.day, day.name);
+            }
+        }
+        Command::New { year, day, name, example } => {
+            let mut scaffold = Scaffold::new(year, day, &name)?;
+            if let Some(path) = &example {
+                let contents = read_to_string(path).map_err(|e| format!("Could not read {}: {e}", path.display()))?;
+                scaffold = scaffold.with_example(contents);
+            }
+            scaffold.create(&workspace_dir())?;
+
+            if example.is_some() {
+                println!("Created {}", scaffold.dir());
+            } else {
+                println!("Created {}, paste the example into {}/src/test-input.txt", scaffold.dir(), scaffold.dir());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn init_tracing(verbose: u8) {
+    let level = match verbose {
+        0 => "warn",
+        1 => "debug",
+        _ => "trace",
+    };
+
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(level));
+
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .without_time()
+        .init();
+}
+
+fn main() {
+    let cli = Cli::parse();
+    init_tracing(cli.verbose);
+
+    if let Err(e) = run(cli) {
+        eprintln!("Error: {e}");
+        process::exit(1);
+    }
+}
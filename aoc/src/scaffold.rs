@@ -0,0 +1,246 @@
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_registry::DEFAULT_YEAR;
+
+use crate::answers::ANSWERS_FILE;
+
+const REGISTRY_FILE: &str = "aoc-registry/src/lib.rs";
+const REGISTRY_MANIFEST: &str = "aoc-registry/Cargo.toml";
+const PYTHON_FILE: &str = "aoc-py/src/lib.rs";
+const PYTHON_MANIFEST: &str = "aoc-py/Cargo.toml";
+
+// A new day's crate, named dN-slug like the others, along with everything
+// that has to know about it: the workspace members and the registry's
+// dependencies and list of days, which is all the runner and wasm need, and
+// for the current year the Python module
+#[derive(Debug, Clone)]
+pub struct Scaffold {
+    pub year: u16,
+    pub day: u8,
+    pub name: String,
+    // Goes in src/test-input.txt
+    pub example: String,
+}
+
+impl Scaffold {
+    pub fn new(year: u16, day: u8, name: &str) -> Result<Self, String> {
+        if !(1..=25).contains(&day) {
+            return Err(format!("There is no day {day}, puzzles go from 1 to 25"));
+        }
+
+        let is_slug = name.split('-').all(|word| !word.is_empty() && word.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()));
+        if !is_slug || !name.starts_with(|c: char| c.is_ascii_lowercase()) {
+            return Err(format!("{name:?} is not a name like pyroclastic-flow"));
+        }
+
+        Ok(Scaffold {
+            year,
+            day,
+            name: name.to_string(),
+            example: String::new(),
+        })
+    }
+
+    pub fn with_example(self, example: impl Into<String>) -> Self {
+        Scaffold {
+            example: example.into(),
+            ..self
+        }
+    }
+
+    // Crate directory, relative to the workspace
+    pub fn dir(&self) -> String {
+        format!("d{}-{}", self.day, self.name)
+    }
+
+    fn crate_name(&self) -> String {
+        self.dir().replace('-', "_")
+    }
+
+    // pyroclastic-flow becomes PyroclasticFlow
+    pub fn type_name(&self) -> String {
+        self.name
+            .split('-')
+            .map(|word| word[..1].to_ascii_uppercase() + &word[1..])
+            .collect()
+    }
+
+    // Writes the crate and registers it, returning the crate's directory
+    pub fn create(&self, workspace: &Path) -> Result<PathBuf, Box<dyn Error>> {
+        let dir = workspace.join(self.dir());
+        if dir.exists() {
+            return Err(format!("{} already exists", self.dir()).into());
+        }
+
+        let registry = read(&workspace.join(REGISTRY_FILE))?;
+        if registry.contains(&format!(">({}, {}, ", self.year, self.day)) {
+            return Err(format!("{} day {} is already in {REGISTRY_FILE}", self.year, self.day).into());
+        }
+
+        // Edit everything in memory first, so nothing is written unless it all fits
+        let members = insert_after_last(&read(&workspace.join("Cargo.toml"))?, |l| l.trim_start().starts_with("\"d"), &format!("    \"{}\",", self.dir()))
+            .ok_or("Could not find the day crates in the workspace members")?;
+        let dependencies = insert_after_last(
+            &read(&workspace.join(REGISTRY_MANIFEST))?,
+            |l| l.contains("path = \"../d"),
+            &format!("{} = {{ path = \"../{}\" }}", self.dir(), self.dir()),
+        )
+        .ok_or(format!("Could not find the day crates in {REGISTRY_MANIFEST}"))?;
+        let registry = self.register(&registry).ok_or(format!("Could not find the imports and DAYS in {REGISTRY_FILE}"))?;
+
+        // The Python module only has the one year, as d1, d2 and so on
+        let python = if self.year == DEFAULT_YEAR {
+            let dependencies = insert_after_last(
+                &read(&workspace.join(PYTHON_MANIFEST))?,
+                |l| l.contains("path = \"../d"),
+                &format!("{} = {{ path = \"../{}\" }}", self.dir(), self.dir()),
+            )
+            .ok_or(format!("Could not find the day crates in {PYTHON_MANIFEST}"))?;
+            let module = insert_after_last(
+                &read(&workspace.join(PYTHON_FILE))?,
+                |l| l.trim_start().starts_with("add_day::<"),
+                &format!("    add_day::<{}::{}>(m, \"d{}\")?;", self.crate_name(), self.type_name(), self.day),
+            )
+            .ok_or(format!("Could not find the days added in {PYTHON_FILE}"))?;
+
+            Some((dependencies, module))
+        } else {
+            None
+        };
+
+        fs::create_dir_all(dir.join("src"))?;
+        fs::write(dir.join("Cargo.toml"), self.manifest())?;
+        fs::write(dir.join("src/lib.rs"), self.solution())?;
+        fs::write(dir.join("src/test-input.txt"), &self.example)?;
+        fs::write(dir.join(ANSWERS_FILE), self.answers())?;
+
+        fs::write(workspace.join("Cargo.toml"), members)?;
+        fs::write(workspace.join(REGISTRY_MANIFEST), dependencies)?;
+        fs::write(workspace.join(REGISTRY_FILE), registry)?;
+        if let Some((dependencies, module)) = python {
+            fs::write(workspace.join(PYTHON_MANIFEST), dependencies)?;
+            fs::write(workspace.join(PYTHON_FILE), module)?;
+        }
+
+        Ok(dir)
+    }
+
+    fn register(&self, registry: &str) -> Option<String> {
+        let type_name = self.type_name();
+        let registry = insert_after_last(registry, |l| l.starts_with("use d"), &format!("use {}::{type_name};", self.crate_name()))?;
+
+        let days = registry.find("pub static DAYS")?;
+        let end = days + registry[days..].find("\n];")?;
+        let entry = format!(
+            "\n    Day::new::<{type_name}>({}, {}, \"{}\", \"{}\"),",
+            self.year,
+            self.day,
+            self.name,
+            self.dir()
+        );
+
+        Some(format!("{}{entry}{}", &registry[..end], &registry[end..]))
+    }
+
+    fn manifest(&self) -> String {
+        format!(
+            r#"[package]
+name = "{}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc-common = {{ path = "../aoc-common" }}
+"#,
+            self.dir()
+        )
+    }
+
+    fn solution(&self) -> String {
+        let type_name = self.type_name();
+        // Without an example the test would only fail until one is pasted in
+        let ignore = if self.example.trim().is_empty() {
+            "#[ignore = \"paste the example into src/test-input.txt\"]\n"
+        } else {
+            ""
+        };
+
+        format!(
+            r#"use aoc_common::{{ParseError, Part, Solution, Unsolved}};
+
+#[test]
+{ignore}fn test_parse_example() {{
+    let example = include_str!("test-input.txt");
+    assert!(!example.trim().is_empty(), "Paste the example into src/test-input.txt");
+    assert!({type_name}::parse(example).is_ok());
+}}
+
+pub struct {type_name};
+
+impl Solution for {type_name} {{
+    type Input = Vec<String>;
+    type AnswerA = Unsolved;
+    type AnswerB = Unsolved;
+
+    // Take each part out once it has an answer
+    const UNSOLVED: &'static [Part] = &[Part::A, Part::B];
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {{
+        Ok(input.lines().map(ToString::to_string).collect())
+    }}
+
+    fn part_a(_lines: &Vec<String>) -> Unsolved {{
+        Unsolved
+    }}
+
+    fn part_b(_lines: &Vec<String>) -> Unsolved {{
+        Unsolved
+    }}
+}}
+"#
+        )
+    }
+
+    fn answers(&self) -> String {
+        format!(
+            r#"# Expected answers for each input, checked by `aoc verify` and the tests
+# generated in aoc/build.rs. Files are relative to this directory.
+year = {}
+day = {}
+
+# Parts without an answer are skipped, fill them in once they are solved
+[[input]]
+file = "src/test-input.txt"
+# a = ""
+# b = ""
+"#,
+            self.year, self.day
+        )
+    }
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("Could not read {}: {e}", path.display()))
+}
+
+// Keeps every line as it was, including the line endings
+fn insert_after_last(contents: &str, matches: impl Fn(&str) -> bool, line: &str) -> Option<String> {
+    let lines = contents.split_inclusive('\n').collect::<Vec<&str>>();
+    let last = lines.iter().rposition(|l| matches(l))?;
+
+    let mut edited = lines[..=last].concat();
+    if !edited.ends_with('\n') {
+        edited.push('\n');
+    }
+    edited.push_str(line);
+    edited.push('\n');
+    edited.push_str(&lines[last + 1..].concat());
+
+    Some(edited)
+}
@@ -0,0 +1,274 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use aoc_common::Part;
+use serde::{Deserialize, Serialize};
+
+use crate::fetch::{FetchError, HttpFetcher};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    // Wrong, without saying which way
+    Wrong,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => "correct".fmt(f),
+            Verdict::TooHigh => "too high".fmt(f),
+            Verdict::TooLow => "too low".fmt(f),
+            Verdict::Wrong => "wrong".fmt(f),
+        }
+    }
+}
+
+// Why an answer was not submitted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved { answer: String },
+    AlreadyTried { verdict: Verdict },
+    // Outside of the range left by earlier too high and too low answers
+    OutOfBounds { low: Option<i128>, high: Option<i128> },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved { answer } => write!(f, "Already solved, the answer is {answer}"),
+            Refusal::AlreadyTried { verdict } => write!(f, "Already tried, it was {verdict}"),
+            Refusal::OutOfBounds { low, high } => {
+                write!(f, "Earlier attempts put the answer ")?;
+                match (low, high) {
+                    (Some(low), Some(high)) => write!(f, "between {low} and {high}"),
+                    (Some(low), None) => write!(f, "above {low}"),
+                    (None, Some(high)) => write!(f, "below {high}"),
+                    (None, None) => write!(f, "anywhere"),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(Refusal),
+    // Submitted too soon after the previous answer, nothing was checked
+    TooSoon(String),
+    // The server's reply didn't match any known verdict
+    Unrecognised(String),
+    Fetch(FetchError),
+    Io(io::Error),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitError::Refused(refusal) => write!(f, "Not submitted. {refusal}"),
+            SubmitError::TooSoon(message) => write!(f, "Not checked, the server asked to wait: {message}"),
+            SubmitError::Unrecognised(message) => write!(f, "Unexpected reply from the server: {message}"),
+            SubmitError::Fetch(e) => e.fmt(f),
+            SubmitError::Io(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for SubmitError {}
+
+impl From<FetchError> for SubmitError {
+    fn from(e: FetchError) -> Self {
+        SubmitError::Fetch(e)
+    }
+}
+
+impl From<io::Error> for SubmitError {
+    fn from(e: io::Error) -> Self {
+        SubmitError::Io(e)
+    }
+}
+
+// Where answers are checked
+pub trait Submitter {
+    fn submit_answer(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<Verdict, SubmitError>;
+}
+
+// Text of the page's <article>, where the verdict is, without the markup
+fn article_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+pub fn parse_verdict(html: &str) -> Result<Verdict, SubmitError> {
+    let text = article_text(html);
+
+    if text.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if text.contains("That's not the right answer") {
+        Ok(if text.contains("too high") {
+            Verdict::TooHigh
+        } else if text.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        })
+    } else if text.contains("You gave an answer too recently") {
+        Err(SubmitError::TooSoon(text))
+    } else {
+        Err(SubmitError::Unrecognised(text))
+    }
+}
+
+impl Submitter for HttpFetcher {
+    fn submit_answer(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<Verdict, SubmitError> {
+        let level = match part {
+            Part::A => "1",
+            Part::B => "2",
+        };
+
+        let html = self.post_form(&format!("/{year}/day/{day}/answer"), &[("level", level), ("answer", answer)])?;
+        parse_verdict(&html)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub answer: String,
+    pub verdict: Verdict,
+    // Seconds since the Unix epoch
+    pub time: u64,
+}
+
+// Every answer submitted for one day, kept so that known wrong answers are
+// never sent twice
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default)]
+    pub a: Vec<Attempt>,
+    #[serde(default)]
+    pub b: Vec<Attempt>,
+}
+
+impl History {
+    // Empty when the file doesn't exist yet
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        if !path.exists() {
+            return Ok(History::default());
+        }
+
+        let contents = fs::read_to_string(path).map_err(|e| format!("Could not read {}: {e}", path.display()))?;
+        Ok(serde_json::from_str(&contents).map_err(|e| format!("Invalid {}: {e}", path.display()))?)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::write(path, serde_json::to_string_pretty(self).map_err(io::Error::other)?)
+    }
+
+    pub fn attempts(&self, part: Part) -> &[Attempt] {
+        match part {
+            Part::A => &self.a,
+            Part::B => &self.b,
+        }
+    }
+
+    pub fn record(&mut self, part: Part, answer: &str, verdict: Verdict) {
+        let attempts = match part {
+            Part::A => &mut self.a,
+            Part::B => &mut self.b,
+        };
+
+        attempts.push(Attempt {
+            answer: answer.to_string(),
+            verdict,
+            time: SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs(),
+        });
+    }
+
+    pub fn solution(&self, part: Part) -> Option<&str> {
+        self.attempts(part)
+            .iter()
+            .find(|a| a.verdict == Verdict::Correct)
+            .map(|a| a.answer.as_str())
+    }
+
+    // Highest answer that was too low and lowest answer that was too high,
+    // the solution is strictly between them
+    pub fn bounds(&self, part: Part) -> (Option<i128>, Option<i128>) {
+        let numbers = |verdict| {
+            self.attempts(part)
+                .iter()
+                .filter(move |a| a.verdict == verdict)
+                .filter_map(|a| a.answer.trim().parse::<i128>().ok())
+        };
+
+        (numbers(Verdict::TooLow).max(), numbers(Verdict::TooHigh).min())
+    }
+
+    pub fn check(&self, part: Part, answer: &str) -> Result<(), Refusal> {
+        if let Some(solution) = self.solution(part) {
+            return Err(Refusal::AlreadySolved {
+                answer: solution.to_string(),
+            });
+        }
+
+        if let Some(attempt) = self.attempts(part).iter().find(|a| a.answer == answer) {
+            return Err(Refusal::AlreadyTried {
+                verdict: attempt.verdict,
+            });
+        }
+
+        let (low, high) = self.bounds(part);
+        if let Ok(n) = answer.trim().parse::<i128>() {
+            if low.is_some_and(|low| n <= low) || high.is_some_and(|high| n >= high) {
+                return Err(Refusal::OutOfBounds { low, high });
+            }
+        }
+
+        Ok(())
+    }
+
+    // Only sends answers that could still be right, and records the verdict
+    pub fn submit(
+        &mut self,
+        submitter: &dyn Submitter,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<Verdict, SubmitError> {
+        let answer = answer.trim();
+        self.check(part, answer).map_err(SubmitError::Refused)?;
+
+        let verdict = submitter.submit_answer(year, day, part, answer)?;
+        self.record(part, answer, verdict);
+
+        Ok(verdict)
+    }
+}
@@ -0,0 +1,22 @@
+use aoc::{
+    answers::{read_answers, verify_input, Status},
+    registry::find_day,
+};
+
+fn check(year: u16, day: u8, file: &str) {
+    let day = find_day(year, day).unwrap();
+    let answers = read_answers(&day.path()).unwrap().unwrap();
+    let expected = answers.inputs.iter().find(|i| i.file == file).unwrap();
+
+    for (part, status) in verify_input(day, expected) {
+        match status {
+            Status::Pass | Status::Missing => {}
+            Status::Fail { expected, actual } => {
+                panic!("Part {part} of {file}:\nexpected {expected}\n  actual {actual}")
+            }
+            Status::Error(e) => panic!("Part {part} of {file}: {e}"),
+        }
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/answer_tests.rs"));
@@ -0,0 +1,56 @@
+mod common;
+
+use std::{
+    fs,
+    time::{Duration, Instant},
+};
+
+use aoc::{
+    cache::{CacheError, InputCache},
+    fetch::{FetchError, Fetcher, HttpFetcher, RateLimiter},
+};
+use common::{serve, temp_dir};
+
+#[test]
+fn fetches_once_then_uses_the_cache() {
+    let (base_url, requests) = serve(vec![(200, "1000\n2000\n")]);
+    let cache = InputCache::new(temp_dir("cache"));
+    let fetcher = HttpFetcher::new(base_url, "secret", RateLimiter::new(Duration::ZERO));
+
+    assert_eq!(cache.load(2022, 1, Some(&fetcher)).unwrap(), "1000\n2000\n");
+    assert_eq!(cache.load(2022, 1, Some(&fetcher)).unwrap(), "1000\n2000\n");
+    assert_eq!(fs::read_to_string(cache.path(2022, 1)).unwrap(), "1000\n2000\n");
+    assert_eq!(*requests.lock().unwrap(), vec!["GET /2022/day/1/input HTTP/1.1 session=secret "]);
+}
+
+#[test]
+fn offline_cache_miss_explains_what_to_do() {
+    let cache = InputCache::new(temp_dir("offline"));
+    let error = cache.load(2022, 3, None).unwrap_err();
+
+    assert!(matches!(error, CacheError::Miss { year: 2022, day: 3, .. }));
+    assert!(error.to_string().contains(&cache.path(2022, 3).display().to_string()));
+    assert!(error.to_string().contains("AOC_SESSION"));
+}
+
+#[test]
+fn rejected_session_is_reported() {
+    let (base_url, _) = serve(vec![(400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.")]);
+    let fetcher = HttpFetcher::new(base_url, "expired", RateLimiter::new(Duration::ZERO));
+
+    let error = fetcher.fetch_input(2022, 2).unwrap_err();
+    assert!(matches!(error, FetchError::Http { status: 400, .. }));
+    assert!(error.to_string().contains("session cookie was rejected"));
+}
+
+#[test]
+fn rate_limit_is_kept_across_limiters() {
+    let state = temp_dir("limiter").join("last-request");
+    let interval = Duration::from_millis(200);
+
+    RateLimiter::new(interval).with_state_file(&state).wait().unwrap();
+    let start = Instant::now();
+    RateLimiter::new(interval).with_state_file(&state).wait().unwrap();
+
+    assert!(start.elapsed() >= Duration::from_millis(150));
+}
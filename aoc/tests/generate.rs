@@ -0,0 +1,51 @@
+use aoc::registry::{find_day, DAYS};
+use aoc_common::Part;
+
+#[test]
+fn test_generated_inputs_parse_and_solve() {
+    for day in DAYS.iter().filter(|d| d.generate.is_some()) {
+        let generate = day.generate.unwrap();
+
+        for seed in 0..5 {
+            let input = generate(12, seed);
+            assert_eq!(input, generate(12, seed), "{} day {} seed {seed} is not repeatable", day.year, day.day);
+
+            for part in [Part::A, Part::B].into_iter().filter(|p| !day.unsolved.contains(p)) {
+                (day.solve)(part, &input).unwrap_or_else(|e| panic!("{} day {} seed {seed}: {e}\n{input}", day.year, day.day));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_generated_seeds_differ() {
+    let generate = find_day(2022, 1).and_then(|d| d.generate).unwrap();
+
+    assert_ne!(generate(20, 1), generate(20, 2));
+}
+
+#[test]
+fn test_generated_hills_reach_the_signal() {
+    let day = find_day(2022, 12).unwrap();
+    let generate = day.generate.unwrap();
+
+    for seed in 0..20 {
+        let steps = (day.solve)(Part::A, &generate(8, seed)).unwrap();
+        assert_ne!(steps, usize::MAX.to_string(), "seed {seed}");
+    }
+}
+
+#[test]
+fn test_generated_packets_leave_out_the_dividers() {
+    let day = find_day(2022, 13).unwrap();
+    let generate = day.generate.unwrap();
+
+    for size in [12, 200, 2000] {
+        for seed in 0..10 {
+            let input = generate(size, seed);
+
+            assert!(!input.lines().any(|l| l == "[[2]]" || l == "[[6]]"), "size {size} seed {seed}");
+            (day.solve)(Part::B, &input).unwrap_or_else(|e| panic!("size {size} seed {seed}: {e}"));
+        }
+    }
+}
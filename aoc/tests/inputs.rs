@@ -0,0 +1,20 @@
+use aoc::registry::{find_day, DAYS};
+use aoc_common::input::InputKind;
+
+// `aoc run` without --input needs exactly one real input to pick
+#[test]
+fn test_one_real_input_per_day() {
+    for day in DAYS {
+        let real = day.inputs().into_iter().filter(|i| i.kind == InputKind::Real).collect::<Vec<_>>();
+        assert_eq!(real.len(), 1, "{} day {}: {:?}", day.year, day.day, real.iter().map(|i| &i.name).collect::<Vec<_>>());
+    }
+}
+
+// Its rule tables are read by the solution, not solved
+#[test]
+fn test_rule_tables_are_not_inputs() {
+    let inputs = find_day(2022, 2).unwrap().inputs();
+    let real = inputs.iter().filter(|i| i.kind == InputKind::Real).map(|i| i.name.as_str()).collect::<Vec<&str>>();
+
+    assert_eq!(real, ["src/strategy.txt"]);
+}
@@ -0,0 +1,110 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+use aoc::{registry::workspace_dir, scaffold::Scaffold};
+
+// A copy of the files the scaffold edits, so the real workspace is left alone
+fn fake_workspace(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-test-{}-{name}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("aoc-registry/src")).unwrap();
+    fs::create_dir_all(dir.join("aoc-py/src")).unwrap();
+
+    for file in FILES {
+        fs::copy(workspace_dir().join(file), dir.join(file)).unwrap();
+    }
+
+    dir
+}
+
+const FILES: [&str; 5] = ["Cargo.toml", "aoc-registry/Cargo.toml", "aoc-registry/src/lib.rs", "aoc-py/Cargo.toml", "aoc-py/src/lib.rs"];
+
+// Each file the scaffold edits, before and after `scaffold` creates its day
+fn edits(workspace: &Path, scaffold: &Scaffold) -> [(String, String); 5] {
+    let read = |file| fs::read_to_string(workspace.join(file)).unwrap();
+
+    let before = FILES.map(read);
+    scaffold.create(workspace).unwrap();
+    let mut after = FILES.map(read).into_iter();
+
+    before.map(|contents| (contents, after.next().unwrap()))
+}
+
+// Whether `line` went in right after the last line that `matches` before
+fn inserted_after_last(before: &str, after: &str, matches: impl Fn(&str) -> bool, line: &str) -> bool {
+    let last = before.lines().rfind(|l| matches(l)).unwrap();
+    after.contains(&format!("{last}\n{line}\n"))
+}
+
+#[test]
+fn test_new_day_is_registered() {
+    let workspace = fake_workspace("scaffold");
+    let scaffold = Scaffold::new(2022, 17, "pyroclastic-flow").unwrap().with_example(">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>\n");
+
+    let [members, dependencies, registry, python_dependencies, python] = edits(&workspace, &scaffold);
+
+    let dir = workspace.join("d17-pyroclastic-flow");
+    assert!(fs::read_to_string(dir.join("Cargo.toml")).unwrap().contains("name = \"d17-pyroclastic-flow\""));
+    let lib = fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+    assert!(lib.contains("impl Solution for PyroclasticFlow"));
+    assert!(lib.contains("#[test]\nfn test_parse_example()"));
+    assert!(fs::read_to_string(dir.join("answers.toml")).unwrap().contains("day = 17"));
+    assert_eq!(fs::read_to_string(dir.join("src/test-input.txt")).unwrap(), scaffold.example);
+
+    let is_day = |l: &str| l.trim_start().starts_with("\"d");
+    assert!(inserted_after_last(&members.0, &members.1, is_day, "    \"d17-pyroclastic-flow\","));
+
+    let is_dependency = |l: &str| l.contains("path = \"../d");
+    let dependency = "d17-pyroclastic-flow = { path = \"../d17-pyroclastic-flow\" }";
+    assert!(inserted_after_last(&dependencies.0, &dependencies.1, is_dependency, dependency));
+    assert!(inserted_after_last(&python_dependencies.0, &python_dependencies.1, is_dependency, dependency));
+
+    let is_import = |l: &str| l.starts_with("use d");
+    assert!(inserted_after_last(&registry.0, &registry.1, is_import, "use d17_pyroclastic_flow::PyroclasticFlow;"));
+    let is_entry = |l: &str| l.starts_with("    Day::new::<");
+    let entry = "    Day::new::<PyroclasticFlow>(2022, 17, \"pyroclastic-flow\", \"d17-pyroclastic-flow\"),";
+    assert!(inserted_after_last(&registry.0, &registry.1, is_entry, entry));
+    assert!(registry.1.contains(&format!("{entry}\n];")));
+
+    let is_added = |l: &str| l.trim_start().starts_with("add_day::<");
+    assert!(inserted_after_last(&python.0, &python.1, is_added, "    add_day::<d17_pyroclastic_flow::PyroclasticFlow>(m, \"d17\")?;"));
+
+    // Once is enough
+    assert!(scaffold.create(&workspace).is_err());
+}
+
+#[test]
+fn test_other_years_stay_out_of_python() {
+    let workspace = fake_workspace("scaffold-other-year");
+    let scaffold = Scaffold::new(2023, 1, "trebuchet").unwrap();
+
+    let [.., registry, python_dependencies, python] = edits(&workspace, &scaffold);
+
+    assert!(registry.1.contains("    Day::new::<Trebuchet>(2023, 1, \"trebuchet\", \"d1-trebuchet\"),\n];"));
+    // There is no example to parse yet
+    let lib = fs::read_to_string(workspace.join("d1-trebuchet/src/lib.rs")).unwrap();
+    assert!(lib.contains("#[test]\n#[ignore = \"paste the example into src/test-input.txt\"]\nfn test_parse_example()"));
+    assert_eq!(python_dependencies.0, python_dependencies.1);
+    assert_eq!(python.0, python.1);
+}
+
+#[test]
+fn test_existing_days_are_left_alone() {
+    let workspace = fake_workspace("scaffold-existing");
+    let registry = fs::read_to_string(workspace.join("aoc-registry/src/lib.rs")).unwrap();
+
+    assert!(Scaffold::new(2022, 16, "another-volcano").unwrap().create(&workspace).is_err());
+    assert!(!workspace.join("d16-another-volcano").exists());
+    assert_eq!(fs::read_to_string(workspace.join("aoc-registry/src/lib.rs")).unwrap(), registry);
+}
+
+#[test]
+fn test_names_are_slugs() {
+    assert_eq!(Scaffold::new(2022, 20, "grove-positioning-system").unwrap().type_name(), "GrovePositioningSystem");
+    assert!(Scaffold::new(2022, 18, "Boiling Boulders").is_err());
+    assert!(Scaffold::new(2022, 18, "boiling--boulders").is_err());
+    assert!(Scaffold::new(2022, 26, "boiling-boulders").is_err());
+}
@@ -0,0 +1,47 @@
+use std::{fs::File, io::BufReader};
+
+use aoc::{
+    answers::read_answers,
+    registry::{find_day, DAYS},
+};
+use aoc_common::{input, Part};
+
+// Against the answers.toml of each day, skipping the inputs its tests skip.
+// Multi-line answers end with a newline in TOML.
+#[test]
+fn test_streamed_answers() {
+    for day in DAYS.iter().filter(|d| d.stream.is_some()) {
+        let stream = day.stream.unwrap();
+        let answers = read_answers(&day.path()).unwrap().unwrap();
+
+        for expected in answers.inputs.iter().filter(|i| i.ignore.is_none()) {
+            for part in [Part::A, Part::B] {
+                let Some(answer) = expected.answer(part) else {
+                    continue;
+                };
+
+                let mut reader = BufReader::new(File::open(input::resolve(day.path(), &expected.file)).unwrap());
+                assert_eq!(
+                    stream(part, &mut reader).unwrap().trim_end(),
+                    answer.trim_end(),
+                    "{} day {} {} part {part}",
+                    day.year,
+                    day.day,
+                    expected.file
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_streamed_errors_point_at_the_line() {
+    let day = find_day(2022, 4).unwrap();
+    let input = "2-4,6-8\n2-3,4-5\n5-7,7-x\n2-8,3-7";
+
+    let streamed = (day.stream.unwrap())(Part::A, &mut input.as_bytes()).unwrap_err();
+    let solved = (day.solve)(Part::A, input).unwrap_err();
+
+    assert_eq!(streamed.line(), Some(3));
+    assert_eq!(streamed.message(), solved.message());
+}
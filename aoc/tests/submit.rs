@@ -0,0 +1,76 @@
+mod common;
+
+use std::{cell::RefCell, time::Duration};
+
+use aoc::{
+    fetch::{HttpFetcher, RateLimiter},
+    submit::{History, Refusal, SubmitError, Submitter, Verdict},
+};
+use aoc_common::Part;
+use common::{serve, temp_dir};
+
+// Knows the right answer and says which way wrong answers are off
+struct FakeSubmitter {
+    solution: i64,
+    submitted: RefCell<Vec<String>>,
+}
+
+impl Submitter for FakeSubmitter {
+    fn submit_answer(&self, _year: u16, _day: u8, _part: Part, answer: &str) -> Result<Verdict, SubmitError> {
+        self.submitted.borrow_mut().push(answer.to_string());
+
+        Ok(match answer.parse::<i64>() {
+            Ok(n) if n == self.solution => Verdict::Correct,
+            Ok(n) if n > self.solution => Verdict::TooHigh,
+            Ok(_) => Verdict::TooLow,
+            Err(_) => Verdict::Wrong,
+        })
+    }
+}
+
+#[test]
+fn history_refuses_answers_that_cant_be_right() {
+    let fake = FakeSubmitter {
+        solution: 1651,
+        submitted: RefCell::new(Vec::new()),
+    };
+    let mut history = History::default();
+    let mut submit = |answer| history.submit(&fake, 2022, 16, Part::A, answer);
+
+    assert_eq!(submit("1708").unwrap(), Verdict::TooHigh);
+    assert!(matches!(
+        submit("1708"),
+        Err(SubmitError::Refused(Refusal::AlreadyTried { verdict: Verdict::TooHigh }))
+    ));
+    assert!(matches!(submit("1800"), Err(SubmitError::Refused(Refusal::OutOfBounds { .. }))));
+    assert_eq!(submit("1500").unwrap(), Verdict::TooLow);
+    assert!(matches!(submit("1400"), Err(SubmitError::Refused(Refusal::OutOfBounds { .. }))));
+    assert_eq!(submit("1651").unwrap(), Verdict::Correct);
+    assert!(matches!(submit("1652"), Err(SubmitError::Refused(Refusal::AlreadySolved { .. }))));
+
+    assert_eq!(*fake.submitted.borrow(), vec!["1708", "1500", "1651"]);
+    assert_eq!(history.bounds(Part::A), (Some(1500), Some(1708)));
+    assert!(history.attempts(Part::B).is_empty());
+
+    let path = temp_dir("history").join("16-attempts.json");
+    history.save(&path).unwrap();
+    assert_eq!(History::load(&path).unwrap(), history);
+}
+
+#[test]
+fn verdicts_are_read_from_the_server() {
+    let (base_url, requests) = serve(vec![
+        (200, "<main><article><p>That's not the right answer; your answer is too low. Please wait...</p></article></main>"),
+        (200, "<main><article><p>You gave an answer too recently; you have to wait. You have 42s left to wait.</p></article></main>"),
+        (200, "<main><article><p>That's the right answer! You are one gold star closer.</p></article></main>"),
+    ]);
+    let fetcher = HttpFetcher::new(base_url, "secret", RateLimiter::new(Duration::ZERO));
+    let mut history = History::default();
+
+    assert_eq!(history.submit(&fetcher, 2022, 1, Part::B, "100").unwrap(), Verdict::TooLow);
+    assert!(matches!(history.submit(&fetcher, 2022, 1, Part::B, "200"), Err(SubmitError::TooSoon(_))));
+    assert_eq!(history.submit(&fetcher, 2022, 1, Part::B, "200").unwrap(), Verdict::Correct);
+
+    assert_eq!(history.attempts(Part::B).len(), 2);
+    assert_eq!(requests.lock().unwrap()[0], "POST /2022/day/1/answer HTTP/1.1 session=secret level=2&answer=100");
+}
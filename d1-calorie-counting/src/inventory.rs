@@ -0,0 +1,330 @@
+use std::{
+    fmt::{self, Display},
+    io::{self, BufRead},
+};
+
+use aoc_common::ParseError;
+use serde::Serialize;
+
+use crate::ElfParser;
+
+#[test]
+fn test_elf_stats() {
+    let elf = Elf::new(0, vec![3000, 1000, 2000, 6000]);
+
+    assert_eq!(elf.total(), 12000);
+    assert_eq!((elf.min(), elf.max()), (Some(1000), Some(6000)));
+    assert_eq!(elf.mean(), Some(3000.0));
+    assert_eq!(elf.median(), Some(2500.0));
+    assert_eq!(Elf::new(1, vec![]).median(), None);
+
+    let heavy = Elf::new(2, vec![u64::MAX, u64::MAX]);
+    assert_eq!(heavy.total(), 2 * u64::MAX as u128);
+    assert_eq!(heavy.mean(), Some(u64::MAX as f64));
+    assert_eq!(heavy.median(), Some(u64::MAX as f64));
+}
+
+#[test]
+fn test_read_inventory() {
+    let elves = read_inventory(include_str!("test-elf-calories.txt").as_bytes()).unwrap();
+
+    assert_eq!(elves.len(), 5);
+    assert_eq!(elves[0], Elf::new(0, vec![1000, 2000, 3000]));
+    assert_eq!(elves[4], Elf::new(4, vec![10000]));
+    assert_eq!(read_inventory("1000\n\n2000\nsnack\n".as_bytes()).unwrap_err().line(), Some(4));
+}
+
+#[test]
+fn test_percentiles() {
+    let totals = [6000, 4000, 11000, 24000, 10000];
+
+    assert_eq!(percentile(&totals, 0), Some(4000));
+    assert_eq!(percentile(&totals, 50), Some(10000));
+    assert_eq!(percentile(&totals, 90), Some(24000));
+    assert_eq!(percentile(&totals, 100), Some(24000));
+    assert_eq!(percentile(&[], 50), None);
+}
+
+#[test]
+fn test_histogram() {
+    let buckets = histogram(&[6000, 4000, 11000, 24000, 10000], 4);
+
+    assert_eq!(
+        buckets.iter().map(|b| (b.from, b.to, b.elves)).collect::<Vec<_>>(),
+        vec![(4000, 9000, 2), (9001, 14001, 2), (14002, 19002, 0), (19003, 24000, 1)]
+    );
+    assert!(histogram(&[], 4).is_empty());
+}
+
+#[test]
+fn test_histogram_extremes() {
+    let ranges = |totals: &[u128], buckets| histogram(totals, buckets).iter().map(|b| (b.from, b.to, b.elves)).collect::<Vec<_>>();
+
+    assert_eq!(ranges(&[0, u128::MAX], 1), vec![(0, u128::MAX, 2)]);
+    assert_eq!(ranges(&[0, u128::MAX], 2), vec![(0, u128::MAX / 2, 1), (u128::MAX / 2 + 1, u128::MAX, 1)]);
+    // Buckets past the largest total are left out
+    assert_eq!(ranges(&[u128::MAX - 1, u128::MAX], 3), vec![(u128::MAX - 1, u128::MAX - 1, 1), (u128::MAX, u128::MAX, 1)]);
+}
+
+#[test]
+fn test_report_formats() {
+    let elves = read_inventory(include_str!("test-elf-calories.txt").as_bytes()).unwrap();
+    let report = Report::new(&elves, 2);
+
+    let csv = report.to_csv();
+    assert_eq!(csv.lines().next(), Some("elf,count,total,min,max,mean,median,items"));
+    assert_eq!(csv.lines().nth(1), Some("1,3,6000,1000,3000,2000,2000,1000 2000 3000"));
+    assert_eq!(csv.lines().count(), 6);
+
+    assert!(report.to_string().contains("p50       10000"));
+}
+
+// One elf's snacks, in the order they are listed. Elves are indexed from 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    pub index: usize,
+    pub items: Vec<u64>,
+}
+
+impl Elf {
+    pub fn new(index: usize, items: Vec<u64>) -> Self {
+        Elf { index, items }
+    }
+
+    // Parsed elves always fit in a u64, but one made with `new` might not
+    pub fn total(&self) -> u128 {
+        self.items.iter().map(|&calories| calories as u128).sum()
+    }
+
+    pub fn count(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn min(&self) -> Option<u64> {
+        self.items.iter().copied().min()
+    }
+
+    pub fn max(&self) -> Option<u64> {
+        self.items.iter().copied().max()
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        (!self.items.is_empty()).then(|| self.total() as f64 / self.count() as f64)
+    }
+
+    // The mean of the middle two for an even number of items
+    pub fn median(&self) -> Option<f64> {
+        let mut sorted = self.items.clone();
+        sorted.sort_unstable();
+
+        let middle = sorted.len() / 2;
+        match sorted.len() {
+            0 => None,
+            n if n.is_multiple_of(2) => Some((sorted[middle - 1] as f64 + sorted[middle] as f64) / 2.0),
+            _ => Some(sorted[middle] as f64),
+        }
+    }
+}
+
+pub fn read_inventory(reader: impl BufRead) -> Result<Vec<Elf>, ParseError> {
+    InventoryParser::new(reader.lines()).collect()
+}
+
+// Every elf with their snacks in turn, from lines such as the ones
+// `BufRead::lines` gives, read as `ElfParser` reads them
+pub struct InventoryParser<I>
+where
+    I: Iterator<Item = io::Result<String>>,
+{
+    elves: ElfParser<I>,
+    elf_index: usize,
+}
+
+impl<I> InventoryParser<I>
+where
+    I: Iterator<Item = io::Result<String>>,
+{
+    pub fn new(source: I) -> InventoryParser<I> {
+        InventoryParser {
+            elves: ElfParser::with_items(source),
+            elf_index: 0,
+        }
+    }
+}
+
+impl<I> Iterator for InventoryParser<I>
+where
+    I: Iterator<Item = io::Result<String>>,
+{
+    type Item = Result<Elf, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let total = self.elves.next()?;
+
+        Some(total.map(|_| {
+            self.elf_index += 1;
+            Elf::new(self.elf_index - 1, self.elves.take_items())
+        }))
+    }
+}
+
+// Nearest rank, so always one of the `values`
+pub fn percentile(values: &[u128], percent: u8) -> Option<u128> {
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+
+    let rank = (percent.min(100) as usize * sorted.len()).div_ceil(100).max(1);
+    sorted.get(rank - 1).copied()
+}
+
+// Elves whose total is from `from` to `to`, inclusive
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Bucket {
+    pub from: u128,
+    pub to: u128,
+    pub elves: usize,
+}
+
+// Up to `buckets` equally wide buckets from the smallest total to the
+// largest. The last bucket ends at the largest total, and buckets that would
+// start past it are left out.
+pub fn histogram(totals: &[u128], buckets: usize) -> Vec<Bucket> {
+    let (Some(&min), Some(&max)) = (totals.iter().min(), totals.iter().max()) else {
+        return Vec::new();
+    };
+    // Totals from 0 to u128::MAX are one more than a u128 can count, which
+    // leaves the buckets one short, so the last one is stretched to fit
+    let width = (max - min).saturating_add(1).div_ceil(buckets.max(1) as u128);
+
+    let mut histogram = (0..buckets.max(1) as u128)
+        .map_while(|i| i.checked_mul(width).filter(|&offset| offset <= max - min))
+        .map(|offset| Bucket {
+            from: min + offset,
+            to: (min + offset).saturating_add(width - 1),
+            elves: 0,
+        })
+        .collect::<Vec<Bucket>>();
+
+    let last = histogram.len() - 1;
+    histogram[last].to = max;
+    for total in totals {
+        histogram[(((total - min) / width) as usize).min(last)].elves += 1;
+    }
+
+    histogram
+}
+
+// Elves are numbered from 1 in reports, as in the puzzle
+#[derive(Debug, Clone, Serialize)]
+pub struct ElfSummary {
+    pub elf: usize,
+    pub count: usize,
+    pub total: u128,
+    pub min: Option<u64>,
+    pub max: Option<u64>,
+    pub mean: Option<f64>,
+    pub median: Option<f64>,
+    pub items: Vec<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Percentile {
+    pub percent: u8,
+    pub total: u128,
+}
+
+const REPORTED_PERCENTILES: [u8; 5] = [10, 25, 50, 75, 90];
+
+// Everything about every elf, along with how their totals are spread
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub elves: Vec<ElfSummary>,
+    pub percentiles: Vec<Percentile>,
+    pub histogram: Vec<Bucket>,
+}
+
+impl Report {
+    pub fn new(elves: &[Elf], buckets: usize) -> Self {
+        let totals = elves.iter().map(Elf::total).collect::<Vec<u128>>();
+
+        Report {
+            elves: elves
+                .iter()
+                .map(|elf| ElfSummary {
+                    elf: elf.index + 1,
+                    count: elf.count(),
+                    total: elf.total(),
+                    min: elf.min(),
+                    max: elf.max(),
+                    mean: elf.mean(),
+                    median: elf.median(),
+                    items: elf.items.clone(),
+                })
+                .collect(),
+            percentiles: REPORTED_PERCENTILES
+                .iter()
+                .filter_map(|&percent| Some(Percentile { percent, total: percentile(&totals, percent)? }))
+                .collect(),
+            histogram: histogram(&totals, buckets),
+        }
+    }
+
+    // One row per elf, with its items separated by spaces in the last column
+    pub fn to_csv(&self) -> String {
+        let optional = |value: Option<String>| value.unwrap_or_default();
+        let mut csv = "elf,count,total,min,max,mean,median,items\n".to_string();
+
+        for elf in &self.elves {
+            let items = elf.items.iter().map(u64::to_string).collect::<Vec<String>>().join(" ");
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{items}\n",
+                elf.elf,
+                elf.count,
+                elf.total,
+                optional(elf.min.map(|m| m.to_string())),
+                optional(elf.max.map(|m| m.to_string())),
+                optional(elf.mean.map(|m| m.to_string())),
+                optional(elf.median.map(|m| m.to_string())),
+            ));
+        }
+
+        csv
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let optional = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+
+        writeln!(f, "{:>8} {:>6} {:>10} {:>8} {:>8} {:>10} {:>10}", "elf", "items", "total", "min", "max", "mean", "median")?;
+        for elf in &self.elves {
+            writeln!(
+                f,
+                "{:>8} {:>6} {:>10} {:>8} {:>8} {:>10} {:>10}",
+                elf.elf,
+                elf.count,
+                elf.total,
+                optional(elf.min.map(|m| m.to_string())),
+                optional(elf.max.map(|m| m.to_string())),
+                optional(elf.mean.map(|m| format!("{m:.1}"))),
+                optional(elf.median.map(|m| format!("{m:.1}"))),
+            )?;
+        }
+
+        writeln!(f)?;
+        for p in &self.percentiles {
+            writeln!(f, "p{:<8} {}", p.percent, p.total)?;
+        }
+
+        // Bars are scaled to the fullest bucket
+        let fullest = self.histogram.iter().map(|b| b.elves).max().unwrap_or(0).max(1);
+        writeln!(f)?;
+        for bucket in &self.histogram {
+            let bar = "#".repeat((bucket.elves * 40).div_ceil(fullest));
+            let line = format!("{:>10} - {:<10} {:>6} {bar}", bucket.from, bucket.to, bucket.elves);
+            writeln!(f, "{}", line.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
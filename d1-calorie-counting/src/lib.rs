@@ -0,0 +1,277 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    error::Error,
+    fs::File,
+    io::{self, BufRead, BufReader},
+    mem,
+    path::Path,
+};
+
+use aoc_common::{
+    generate::{Generate, Rng},
+    stream::Stream,
+    ParseError, Solution,
+};
+
+pub mod inventory;
+
+#[test]
+fn test_read_file() {
+    let expected_elf_calories = vec![6000, 4000, 11000, 24000, 10000];
+
+    let actual_elf_calories = read_file(aoc_common::input_path!("src/test-elf-calories.txt")).unwrap();
+
+    assert_eq!(expected_elf_calories, actual_elf_calories);
+}
+
+#[test]
+fn test_top_n() {
+    let calories = [6000, 4000, 11000, 24000, 10000];
+
+    assert_eq!(top_n(calories, 3), vec![(3, 24000), (2, 11000), (4, 10000)]);
+    assert_eq!(top_n(calories, 10).len(), 5);
+    assert_eq!(top_n(calories, 0), vec![]);
+    assert_eq!(top_n(calories, usize::MAX).len(), 5);
+    // Earlier elves come first on ties
+    assert_eq!(top_n([5, 7, 5, 7], 3), vec![(1, 7), (3, 7), (0, 5)]);
+}
+
+#[test]
+fn test_top_elves() {
+    let input = include_str!("test-elf-calories.txt");
+
+    assert_eq!(top_elves(input.as_bytes(), 2).unwrap(), vec![(3, 24000), (2, 11000)]);
+    assert_eq!(top_elves("1000\n\nlots\n".as_bytes(), 2).unwrap_err().line(), Some(3));
+}
+
+#[test]
+fn test_blank_line_runs_and_zero_calorie_elves() {
+    let input = "\n\n1000\n2000\n\n\n\n0\n\n3000\n\n";
+
+    assert_eq!(read_elf_calories(input.as_bytes()).unwrap(), vec![3000, 0, 3000]);
+}
+
+#[test]
+fn test_windows_line_endings() {
+    let input = include_str!("test-elf-calories.txt").replace('\n', "\r\n");
+
+    assert_eq!(read_elf_calories(input.as_bytes()).unwrap(), vec![6000, 4000, 11000, 24000, 10000]);
+    assert_eq!(CalorieCounting::solve(aoc_common::Part::B, &input).unwrap(), "45000");
+}
+
+#[test]
+fn test_u64_totals() {
+    let input = "4000000000\n4000000000\n\n1";
+
+    assert_eq!(read_elf_calories(input.as_bytes()).unwrap(), vec![8000000000, 1]);
+
+    let error = read_elf_calories("18446744073709551615\n1\n".as_bytes()).unwrap_err();
+    assert_eq!(error.line(), Some(2));
+
+    let input = "18446744073709551615\n\n18446744073709551615\n\n18446744073709551615\n";
+    assert_eq!(CalorieCounting::solve(aoc_common::Part::A, input).unwrap(), "18446744073709551615");
+    assert_eq!(CalorieCounting::solve(aoc_common::Part::B, input).unwrap(), "55340232221128654845");
+    assert_eq!(CalorieCounting::stream_b(input.as_bytes()).unwrap(), 3 * u64::MAX as u128);
+}
+
+#[test]
+fn test_non_numeric_entries_point_at_the_line() {
+    let error = read_elf_calories("1000\n\n\n2000\n  20OO\r\n3000\n".as_bytes()).unwrap_err();
+
+    assert_eq!(error.line(), Some(5));
+    assert_eq!(error.column(), Some(3));
+    assert!(error.message().starts_with("Not a calorie count"));
+}
+
+pub fn read_file(filename: impl AsRef<Path>) -> Result<Vec<u64>, Box<dyn Error>> {
+    let file = File::open(filename)?;
+    Ok(read_elf_calories(BufReader::new(file))?)
+}
+
+pub fn read_elf_calories(reader: impl BufRead) -> Result<Vec<u64>, ParseError> {
+    ElfParser::new(reader.lines()).collect()
+}
+
+// The `n` largest items along with their position in `items`, from the
+// largest, with earlier items first on ties. Only the best `n` so far are
+// kept, in a min-heap, so `items` can be as long as it likes. The heap grows
+// as it fills rather than being sized from `n`, which comes from the user.
+pub fn top_n<T: Ord>(items: impl IntoIterator<Item = T>, n: usize) -> Vec<(usize, T)> {
+    let mut best = BinaryHeap::new();
+
+    for (i, item) in items.into_iter().enumerate() {
+        let ranked = Reverse((item, Reverse(i)));
+
+        if best.len() < n {
+            best.push(ranked);
+        } else if best.peek().is_some_and(|worst| ranked < *worst) {
+            best.pop();
+            best.push(ranked);
+        }
+    }
+
+    best.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((item, Reverse(i)))| (i, item))
+        .collect()
+}
+
+// Every elf's total fits in a u64, but a few of them together might not
+pub fn total_calories(elves: &[(usize, u64)]) -> u128 {
+    elves.iter().map(|&(_, calories)| calories as u128).sum()
+}
+
+// The `n` elves carrying the most calories, as (elf index from 0, calories)
+pub fn top_elves(reader: impl BufRead, n: usize) -> Result<Vec<(usize, u64)>, ParseError> {
+    let mut error = None;
+    let elf_calories = ElfParser::new(reader.lines()).map_while(|calories| calories.map_err(|e| error = Some(e)).ok());
+
+    let top = top_n(elf_calories, n);
+
+    match error {
+        Some(e) => Err(e),
+        None => Ok(top),
+    }
+}
+
+// Total calories of each elf in turn, from lines such as the ones
+// `BufRead::lines` gives. Elves are separated by blank lines. Only the total
+// is kept of the elf being read, unless the items are asked for too, as
+// `InventoryParser` does.
+pub struct ElfParser<I>
+where
+    I: Iterator<Item = io::Result<String>>,
+{
+    line_number: usize,
+    source: I,
+    // Every item of the elf last read, when they are kept
+    items: Option<Vec<u64>>,
+}
+
+impl<I> ElfParser<I>
+where
+    I: Iterator<Item = io::Result<String>>,
+{
+    pub fn new(source: I) -> ElfParser<I> {
+        ElfParser {
+            line_number: 0,
+            source,
+            items: None,
+        }
+    }
+
+    pub fn with_items(source: I) -> ElfParser<I> {
+        ElfParser {
+            items: Some(Vec::new()),
+            ..ElfParser::new(source)
+        }
+    }
+
+    // The items of the elf last read, empty unless they are kept
+    pub fn take_items(&mut self) -> Vec<u64> {
+        self.items.as_mut().map(mem::take).unwrap_or_default()
+    }
+}
+
+impl<I> Iterator for ElfParser<I>
+where
+    I: Iterator<Item = io::Result<String>>,
+{
+    type Item = Result<u64, ParseError>;
+
+    // Any run of blank lines ends an elf, and an elf can carry nothing but
+    // zero calorie snacks. Surrounding whitespace, such as the \r of Windows
+    // line endings, is ignored.
+    fn next(&mut self) -> Option<Self::Item> {
+        // None until the elf's first snack
+        let mut total: Option<u64> = None;
+        if let Some(items) = &mut self.items {
+            items.clear();
+        }
+
+        for line_or_error in self.source.by_ref() {
+            self.line_number += 1;
+
+            let line = match line_or_error {
+                Ok(line) => line,
+                Err(e) => return Some(Err(ParseError::at_line(self.line_number, e.to_string()))),
+            };
+
+            let entry = line.trim();
+            if entry.is_empty() {
+                match total {
+                    Some(total) => return Some(Ok(total)),
+                    None => continue,
+                }
+            }
+
+            let calories = match entry.parse::<u64>() {
+                Ok(calories) => calories,
+                Err(e) => {
+                    return Some(Err(ParseError::at_token(self.line_number, &line, entry, format!("Not a calorie count: {e}"))))
+                }
+            };
+            // Checked here so that Elf::total can't overflow either
+            total = match total.unwrap_or(0).checked_add(calories) {
+                Some(total) => Some(total),
+                None => return Some(Err(ParseError::in_line(self.line_number, &line, "The elf carries too many calories to count"))),
+            };
+
+            if let Some(items) = &mut self.items {
+                items.push(calories);
+            }
+        }
+
+        total.map(Ok)
+    }
+}
+
+pub struct CalorieCounting;
+
+impl Solution for CalorieCounting {
+    type Input = Vec<u64>;
+    type AnswerA = u64;
+    type AnswerB = u128;
+
+    // Total calories of each elf, in the order of the input
+    fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+        read_elf_calories(input.as_bytes())
+    }
+
+    // Greatest Elf Calories
+    fn part_a(elf_calories: &Vec<u64>) -> u64 {
+        elf_calories.iter().copied().max().unwrap_or(0)
+    }
+
+    // Greatest three Elf Calories
+    fn part_b(elf_calories: &Vec<u64>) -> u128 {
+        total_calories(&top_n(elf_calories.iter().copied(), 3))
+    }
+}
+
+// Only the best elves are kept
+impl Stream for CalorieCounting {
+    fn stream_a(reader: impl BufRead) -> Result<u64, ParseError> {
+        Ok(top_elves(reader, 1)?.first().map_or(0, |&(_, calories)| calories))
+    }
+
+    fn stream_b(reader: impl BufRead) -> Result<u128, ParseError> {
+        Ok(total_calories(&top_elves(reader, 3)?))
+    }
+}
+
+// `size` elves carrying one to six snacks each
+impl Generate for CalorieCounting {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| {
+                (0..rng.range(1..7))
+                    .map(|_| rng.range(1000..10000).to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            })
+            .collect::<Vec<String>>()
+            .join("\n\n")
+    }
+}
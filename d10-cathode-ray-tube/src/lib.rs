@@ -0,0 +1,230 @@
+use std::{
+    fmt::Display,
+    io::BufRead,
+};
+
+use aoc_common::{
+    render::{Cell, Frame, Recorder, Render, Visualize},
+    stream::{LineParser, Stream},
+    Grid, ParseError, Part, Solution,
+};
+use tracing::trace;
+
+#[derive(Debug)]
+pub enum Operation {
+    Noop,
+    Addx(i32),
+}
+
+// The value of x during every cycle, starting from cycle 0
+pub fn process_ops(ops: &[Operation]) -> Vec<i32> {
+    let mut x = 1;
+    let mut x_history = Vec::new();
+
+    x_history.push(x);
+
+    for op in ops {
+        trace!(cycle = x_history.len(), x, ?op);
+
+        match op {
+            Operation::Noop => {
+                x_history.push(x);
+            },
+            Operation::Addx(dx) => {
+                x_history.push(x);
+                x_history.push(x);
+                x += *dx;
+            },
+        }
+    }
+
+    x_history
+}
+
+pub fn parse_ops(input: &str) -> Result<Vec<Operation>, ParseError> {
+    input.lines().enumerate().map(|(i, line)| parse_op(i + 1, line)).collect()
+}
+
+fn parse_op(line_number: usize, line: &str) -> Result<Operation, ParseError> {
+    let op = match line.split_whitespace().collect::<Vec<&str>>()[..] {
+        ["noop"] => Operation::Noop,
+        ["addx", n] => Operation::Addx(
+            n.parse()
+                .map_err(|_| ParseError::at_token(line_number, line, n, "Invalid addx value"))?,
+        ),
+        _ => return Err(ParseError::in_line(line_number, line, "Invalid operation")),
+    };
+
+    Ok(op)
+}
+
+// Calls `during` with the value of x during every cycle from 1, without
+// keeping the history, and on until the screen has been drawn
+fn stream_cycles(ops: impl Iterator<Item = Result<Operation, ParseError>>, mut during: impl FnMut(usize, i32)) -> Result<(), ParseError> {
+    let (mut x, mut last) = (1, 1);
+    let mut cycle = 0;
+
+    for op in ops {
+        let (cycles, dx) = match op? {
+            Operation::Noop => (1, 0),
+            Operation::Addx(dx) => (2, dx),
+        };
+
+        for _ in 0..cycles {
+            cycle += 1;
+            during(cycle, x);
+        }
+
+        last = x;
+        x += dx;
+    }
+
+    // Like x_during, the last addx never takes effect
+    while cycle < CRT_ROWS * CRT_COLS {
+        cycle += 1;
+        during(cycle, last);
+    }
+
+    Ok(())
+}
+
+// x keeps its last value once the program has finished
+fn x_during(history: &[i32], cycle: usize) -> i32 {
+    history.get(cycle).or(history.last()).copied().unwrap_or(1)
+}
+
+const CRT_ROWS: usize = 6;
+const CRT_COLS: usize = 40;
+
+// The screen, drawn one pixel per cycle from the top left
+pub struct Crt {
+    pixels: Grid<bool>,
+    cycle: usize,
+}
+
+impl Crt {
+    pub fn new() -> Self {
+        Crt {
+            pixels: Grid::new(CRT_ROWS, CRT_COLS, false),
+            cycle: 0,
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.cycle >= CRT_ROWS * CRT_COLS
+    }
+
+    // Draws the next pixel, lit when the sprite centred on `x` covers it
+    pub fn draw(&mut self, x: i32) {
+        let (row, col) = (self.cycle / CRT_COLS, self.cycle % CRT_COLS);
+        self.pixels[(row, col)] = (x - 1..=x + 1).contains(&(col as i32));
+        self.cycle += 1;
+    }
+
+    pub fn draw_all(history: &[i32]) -> Self {
+        let mut crt = Crt::new();
+        while !crt.is_done() {
+            crt.draw(x_during(history, crt.cycle + 1));
+        }
+        crt
+    }
+}
+
+impl Default for Crt {
+    fn default() -> Self {
+        Crt::new()
+    }
+}
+
+impl Display for Crt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.pixels.map(|&lit| if lit { '#' } else { '.' }).fmt(f)
+    }
+}
+
+// The next pixel to be drawn is highlighted
+impl Render for Crt {
+    fn render(&self) -> Frame {
+        let beam = (self.cycle / CRT_COLS, self.cycle % CRT_COLS);
+
+        Frame::from_fn(CRT_ROWS, CRT_COLS, |pos| match self.pixels[pos] {
+            _ if pos == beam => Cell::new('@', [230, 60, 60]),
+            true => Cell::new('#', [120, 255, 120]),
+            false => Cell::blank('.'),
+        })
+    }
+}
+
+pub struct CathodeRayTube;
+
+impl Solution for CathodeRayTube {
+    type Input = Vec<Operation>;
+    type AnswerA = i32;
+    type AnswerB = String;
+
+    fn parse(input: &str) -> Result<Vec<Operation>, ParseError> {
+        parse_ops(input)
+    }
+
+    // Sum of the signal strengths
+    fn part_a(ops: &Vec<Operation>) -> i32 {
+        let cycles: &[usize] = &[20, 60, 100, 140, 180, 220];
+
+        let history = process_ops(ops);
+
+        cycles.iter()
+              .map(|&c| (c as i32) * x_during(&history, c))
+              .sum()
+    }
+
+    // Image drawn on the CRT
+    fn part_b(ops: &Vec<Operation>) -> String {
+        Crt::draw_all(&process_ops(ops)).to_string()
+    }
+}
+
+impl Stream for CathodeRayTube {
+    fn stream_a(reader: impl BufRead) -> Result<i32, ParseError> {
+        let mut signal_strength = 0;
+
+        stream_cycles(LineParser::new(reader.lines(), parse_op), |cycle, x| {
+            if cycle % 40 == 20 && cycle <= 220 {
+                signal_strength += cycle as i32 * x;
+            }
+        })?;
+
+        Ok(signal_strength)
+    }
+
+    fn stream_b(reader: impl BufRead) -> Result<String, ParseError> {
+        let mut crt = Crt::new();
+
+        stream_cycles(LineParser::new(reader.lines(), parse_op), |_, x| {
+            if !crt.is_done() {
+                crt.draw(x);
+            }
+        })?;
+
+        Ok(crt.to_string())
+    }
+}
+
+impl Visualize for CathodeRayTube {
+    // The image being drawn, one frame per cycle. Part A has nothing to show.
+    fn frames(ops: &Vec<Operation>, part: Part, every: usize) -> Vec<Frame> {
+        if part == Part::A {
+            return Vec::new();
+        }
+
+        let history = process_ops(ops);
+        let mut crt = Crt::new();
+        let mut recorder = Recorder::new(every);
+
+        while !crt.is_done() {
+            recorder.record(|| crt.render());
+            crt.draw(x_during(&history, crt.cycle + 1));
+        }
+
+        recorder.finish(|| crt.render())
+    }
+}
@@ -0,0 +1,92 @@
+use aoc_common::{ParseError, Solution};
+use monkey::{Monkey, MonkeyManager};
+use tracing::debug;
+pub mod monkey;
+
+// Monkeys are described by six lines each, separated by blank lines
+pub fn read_monkeys(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let mut monkeys = Vec::new();
+    let mut lines = input.lines().enumerate();
+
+    while let Some((mut i, mut line)) = lines.next() {
+        if line.is_empty() {
+            match lines.next() {
+                Some((j, next_line)) => (i, line) = (j, next_line),
+                None => break,
+            }
+        }
+
+        let monkey_line = line;
+
+        let mut next_line = || {
+            lines
+                .next()
+                .map(|(_, l)| l)
+                .ok_or_else(|| ParseError::at_line(i + 1, "Monkey description ends early"))
+        };
+
+        let starting_items_line = next_line()?;
+        let operation_line = next_line()?;
+        let test_line = next_line()?;
+        let if_true_line = next_line()?;
+        let if_false_line = next_line()?;
+
+        let monkey = Monkey::build(
+            monkey_line,
+            starting_items_line,
+            operation_line,
+            test_line,
+            if_true_line,
+            if_false_line,
+        )
+        .ok_or_else(|| ParseError::in_line(i + 1, monkey_line, "Invalid monkey description"))?;
+
+        monkeys.push((i, monkey));
+    }
+
+    if monkeys.len() < 2 {
+        return Err(ParseError::new("Monkey business needs at least two monkeys"));
+    }
+
+    for (i, monkey) in &monkeys {
+        let (true_monkey_id, false_monkey_id) = monkey.target_monkey_ids();
+
+        if true_monkey_id.max(false_monkey_id) >= monkeys.len() {
+            return Err(ParseError::at_line(i + 1, "Monkey throws to a monkey that does not exist"));
+        }
+    }
+
+    Ok(monkeys.into_iter().map(|(_, m)| m).collect())
+}
+
+fn monkey_business(monkeys: &[Monkey], worry_divisor: usize, rounds: usize) -> usize {
+    let mut manager = MonkeyManager::new(worry_divisor);
+    monkeys.iter().cloned().for_each(|m| manager.add_monkey(m));
+
+    for round in 1..=rounds {
+        manager.simulate_round();
+        debug!(round, "items held:\n{manager}");
+    }
+
+    manager.monkey_business()
+}
+
+pub struct MonkeyInTheMiddle;
+
+impl Solution for MonkeyInTheMiddle {
+    type Input = Vec<Monkey>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+        read_monkeys(input)
+    }
+
+    fn part_a(monkeys: &Vec<Monkey>) -> usize {
+        monkey_business(monkeys, 3, 20)
+    }
+
+    fn part_b(monkeys: &Vec<Monkey>) -> usize {
+        monkey_business(monkeys, 1, 10000)
+    }
+}
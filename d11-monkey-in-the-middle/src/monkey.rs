@@ -1,8 +1,9 @@
-use std::{fmt::Display, mem};
+use std::fmt::Display;
 
 use regex::Regex;
+use tracing::trace;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Monkey {
     id: usize,
     operation: MonkeyOperation,
@@ -80,7 +81,12 @@ impl Monkey {
         let arg1 = caps.name("arg1").unwrap().as_str();
         let arg2 = caps.name("arg2").unwrap().as_str();
 
-        let operation = MonkeyOperation::build(op, arg1, arg2).expect("Valid MonkeyOperation");
+        let operation = MonkeyOperation::build(op, arg1, arg2)?;
+
+        // Every item would be divided by zero
+        if test_number == 0 {
+            return None;
+        }
 
         Some(Monkey {
             id,
@@ -94,7 +100,7 @@ impl Monkey {
     }
 
     pub fn throw_items(&mut self, worry_level: usize) -> Vec<MonkeyThrow> {
-        let items = mem::replace(&mut self.items, Vec::new());
+        let items = std::mem::take(&mut self.items);
 
         self.inspected_items += items.len();
 
@@ -106,8 +112,28 @@ impl Monkey {
             .collect()
     }
 
+    pub fn id(&self) -> MonkeyId {
+        self.id
+    }
+
+    pub fn items(&self) -> &[usize] {
+        &self.items
+    }
+
+    pub fn operation(&self) -> &MonkeyOperation {
+        &self.operation
+    }
+
+    pub fn test_number(&self) -> usize {
+        self.test_number
+    }
+
+    pub fn target_monkey_ids(&self) -> (MonkeyId, MonkeyId) {
+        (self.true_monkey_id, self.false_monkey_id)
+    }
+
     fn find_suitable_monkey_id(&self, item: usize) -> MonkeyId {
-        if item % self.test_number == 0 {
+        if item.is_multiple_of(self.test_number) {
             self.true_monkey_id
         } else {
             self.false_monkey_id
@@ -149,6 +175,7 @@ impl MonkeyManager {
 
             for MonkeyThrow { monkey_id, item } in throws {
                 let managed_item = item % self.common_divisor;
+                trace!(from = i, to = monkey_id, item = managed_item, "throw");
                 self.monkeys[monkey_id].add_item(managed_item);
             }
         }
@@ -167,8 +194,6 @@ impl MonkeyManager {
 
         insp.sort_by(|(_, a), (_, b)| b.cmp(a));
 
-        println!("{insp:?}");
-
         insp[0].1 * insp[1].1
     }
 }
@@ -183,8 +208,8 @@ impl Display for MonkeyManager {
                 .items
                 .iter()
                 .map(usize::to_string)
-                .intersperse(", ".to_string())
-                .collect::<String>();
+                .collect::<Vec<String>>()
+                .join(", ");
             s.push_str(format!("Monkey {id}: {items}\n").as_str());
         }
 
@@ -192,7 +217,7 @@ impl Display for MonkeyManager {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct MonkeyOperation(MonkeyOperator, MonkeyOperand, MonkeyOperand);
 
 impl MonkeyOperation {
@@ -243,13 +268,29 @@ impl MonkeyOperation {
     }
 }
 
-#[derive(Debug)]
+// As written in the notes, such as old * 19
+impl Display for MonkeyOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let operand = |o: &MonkeyOperand| match o {
+            MonkeyOperand::Old => "old".to_string(),
+            MonkeyOperand::Literal(x) => x.to_string(),
+        };
+        let operator = match self.0 {
+            MonkeyOperator::Add => '+',
+            MonkeyOperator::Mult => '*',
+        };
+
+        write!(f, "{} {operator} {}", operand(&self.1), operand(&self.2))
+    }
+}
+
+#[derive(Debug, Clone)]
 pub enum MonkeyOperand {
     Old,
     Literal(usize),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum MonkeyOperator {
     Add,
     Mult,
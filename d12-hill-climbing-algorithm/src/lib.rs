@@ -0,0 +1,237 @@
+use std::collections::HashSet;
+
+use aoc_common::{
+    generate::{Generate, Rng},
+    grid::Pos,
+    render::{Cell, Frame, Recorder, Render, Visualize},
+    Grid, ParseError, Part, Solution,
+};
+use itertools::Itertools;
+
+pub fn parse_heightmap(input: &str) -> Result<Heightmap, ParseError> {
+    let (a_i32, z_i32) = ('a' as i32, 'z' as i32);
+
+    let mut start = None;
+    let mut end = None;
+
+    let grid = Grid::parse_chars(input, |pos, c| {
+        if c == 'S' {
+            start = Some(pos);
+            Ok(0)
+        } else if c == 'E' {
+            end = Some(pos);
+            Ok(z_i32 - a_i32)
+        } else if c.is_ascii_lowercase() {
+            Ok(c as i32 - a_i32)
+        } else {
+            Err(ParseError::new(format!("Invalid character {c:?}")))
+        }
+    })?;
+
+    Ok(Heightmap {
+        grid,
+        start: start.ok_or_else(|| ParseError::new("There is no start position S"))?,
+        end: end.ok_or_else(|| ParseError::new("There is no best signal position E"))?,
+    })
+}
+
+pub struct Heightmap {
+    grid: Grid<i32>,
+    start: Pos,
+    end: Pos,
+}
+
+impl Heightmap {
+    // Heights from 0 at a to 25 at z
+    pub fn grid(&self) -> &Grid<i32> {
+        &self.grid
+    }
+
+    pub fn start(&self) -> Pos {
+        self.start
+    }
+
+    pub fn end(&self) -> Pos {
+        self.end
+    }
+
+    pub fn find_shortest_path(&self, start: Pos, end: Pos) -> usize {
+        let mut visited: HashSet<Pos> = HashSet::new();
+        let mut queue: Vec<(Pos, usize)> = Vec::new();
+
+        queue.push((start, 0));
+        visited.insert(start);
+
+        while !queue.is_empty() {
+            let (node, dist) = queue.remove(0);
+
+            if node == end {
+                return dist;
+            }
+
+            let unvisited_paths = self
+                .grid
+                .neighbours4(node)
+                .filter(|p| !visited.contains(p))
+                .filter(|&p| self.grid[p] - 1 <= self.grid[node])
+                .map(|p| (p, dist + 1))
+                .collect_vec();
+
+            unvisited_paths.iter().for_each(|(p, _)| {
+                visited.insert(*p);
+            });
+
+            queue.extend(unvisited_paths);
+        }
+
+        usize::MAX
+    }
+
+    pub fn lowest_points(&self) -> Vec<Pos> {
+        self.grid
+            .iter()
+            .filter(|&(_, &h)| h == 0)
+            .map(|(p, _)| p)
+            .collect_vec()
+    }
+
+    // Breadth first search from every position in `starts` at once, calling
+    // `on_layer` with everything reached after each step until `end` is
+    pub fn explore(&self, starts: Vec<Pos>, mut on_layer: impl FnMut(&HashSet<Pos>)) {
+        let mut visited: HashSet<Pos> = starts.iter().copied().collect();
+        let mut layer = starts;
+
+        on_layer(&visited);
+
+        while !layer.is_empty() && !visited.contains(&self.end) {
+            let mut next = Vec::new();
+
+            for node in layer {
+                for p in self.grid.neighbours4(node) {
+                    if self.grid[p] - 1 <= self.grid[node] && visited.insert(p) {
+                        next.push(p);
+                    }
+                }
+            }
+
+            layer = next;
+            on_layer(&visited);
+        }
+    }
+
+    fn cell(&self, pos: Pos, explored: bool) -> Cell {
+        if pos == self.start {
+            return Cell::new('S', [230, 60, 60]);
+        }
+
+        if pos == self.end {
+            return Cell::new('E', [230, 60, 60]);
+        }
+
+        let height = self.grid[pos];
+        let ch = char::from(b'a' + height as u8);
+        // Darker green in the valleys, white on the summit
+        let light = (height * 255 / 25) as u8;
+
+        if explored {
+            Cell::new(ch, [light / 3, light / 2, 150 + light / 3])
+        } else {
+            Cell::new(ch, [light / 2, 100 + light / 2 + light / 8, light / 2])
+        }
+    }
+}
+
+impl Render for Heightmap {
+    fn render(&self) -> Frame {
+        Frame::from_fn(self.grid.rows(), self.grid.cols(), |p| self.cell(p, false))
+    }
+}
+
+// Steps from the start position to the best signal
+fn solve_a(hm: &Heightmap) -> usize {
+    hm.find_shortest_path(hm.start, hm.end)
+}
+
+// Fewest steps from any of the lowest points
+fn solve(hm: &Heightmap) -> usize {
+    hm.lowest_points()
+        .into_iter()
+        .map(|p| hm.find_shortest_path(p, hm.end))
+        .min()
+        .unwrap_or(usize::MAX)
+}
+
+pub struct HillClimbingAlgorithm;
+
+impl Solution for HillClimbingAlgorithm {
+    type Input = Heightmap;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Heightmap, ParseError> {
+        parse_heightmap(input)
+    }
+
+    fn part_a(hm: &Heightmap) -> usize {
+        solve_a(hm)
+    }
+
+    fn part_b(hm: &Heightmap) -> usize {
+        solve(hm)
+    }
+}
+
+impl Visualize for HillClimbingAlgorithm {
+    // The search spreading out one step per frame, from the start for part A
+    // and from all of the lowest points for part B
+    fn frames(hm: &Heightmap, part: Part, every: usize) -> Vec<Frame> {
+        let starts = match part {
+            Part::A => vec![hm.start],
+            Part::B => hm.lowest_points(),
+        };
+
+        let render = |visited: &HashSet<Pos>| {
+            Frame::from_fn(hm.grid.rows(), hm.grid.cols(), |p| hm.cell(p, visited.contains(&p)))
+        };
+
+        let mut recorder = Recorder::new(every);
+        let mut last = HashSet::new();
+
+        hm.explore(starts, |visited| {
+            recorder.record(|| render(visited));
+            last = visited.clone();
+        });
+
+        recorder.finish(|| render(&last))
+    }
+}
+
+impl Generate for HillClimbingAlgorithm {
+    // `size` rows of random heights with a winding path from S on the left
+    // edge to E on the right one, climbing evenly so E is always reachable
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let rows = size.max(2);
+        let cols = rng.range(30..60) as usize;
+
+        // Down each column to a random row, then one step to the right
+        let mut path = Vec::new();
+        let mut row = rng.below(rows);
+        for col in 0..cols {
+            let target = rng.below(rows);
+            path.push((row, col));
+            while row != target {
+                row = if row < target { row + 1 } else { row - 1 };
+                path.push((row, col));
+            }
+        }
+
+        let mut grid = Grid::from_fn(rows, cols, |_| char::from(b'a' + rng.below(26) as u8));
+        for (i, &pos) in path.iter().enumerate() {
+            grid[pos] = char::from(b'a' + (i * 25 / (path.len() - 1)) as u8);
+        }
+        grid[path[0]] = 'S';
+        grid[path[path.len() - 1]] = 'E';
+
+        grid.to_string()
+    }
+}
@@ -0,0 +1,214 @@
+use std::sync::LazyLock;
+
+use aoc_common::{
+    generate::{Generate, Rng},
+    ParseError, Solution,
+};
+use packet::compare_packets;
+use regex::Regex;
+use tracing::trace;
+
+use crate::packet::Packet;
+
+pub mod packet;
+
+#[test]
+fn test_decoder_key_only_counts_the_added_dividers() {
+    let pairs = parse_packet_pairs("[[2]]\n[[6]]\n\n[1]\n[[6]]").unwrap();
+
+    // Sorted, the added dividers come 2nd and 4th: [1] [[2]] [[2]] [[6]] [[6]] [[6]]
+    assert_eq!(find_decoder_key(&pairs), 2 * 4);
+}
+
+enum ParseListState {
+    Unknown,
+    Number,
+    List,
+}
+
+fn parse_list(s: &str) -> Result<Packet, ParseError> {
+    use ParseListState::*;
+    let mut ps = Vec::new();
+    let chars: Vec<char> = s.chars().collect();
+
+    let mut state = Unknown;
+    let mut new_packet = String::new();
+    let mut bc = 0;
+
+    for i in 1..chars.len() {
+        let c = chars[i];
+
+        if matches!(state, Unknown) {
+            match c {
+                '[' => state = List,
+                c if c.is_numeric() => state = Number,
+                c => return Err(ParseError::new(format!("Unexpected {c:?} in {s}"))),
+            }
+        }
+
+        if bc == 0 && (c == ',' || i == chars.len() - 1) {
+            ps.push(parse_packet(new_packet.as_str())?);
+            state = Unknown;
+            bc = 0;
+            new_packet = String::new();
+            continue;
+        }
+
+        if matches!(state, List) {
+            if c == '[' {
+                bc += 1;
+            } else if c == ']' {
+                bc -= 1;
+            }
+
+            if bc < 0 {
+                return Err(ParseError::new(format!("Unbalanced brackets in {s}")));
+            }
+
+            new_packet.push(chars[i])
+        }
+
+        if matches!(state, Number) {
+            new_packet.push(chars[i])
+        }
+    }
+
+    // Anything left over was never closed
+    if !new_packet.is_empty() {
+        return Err(ParseError::new(format!("Unbalanced brackets in {s}")));
+    }
+
+    Ok(Packet::List(ps))
+}
+
+static NUMBER: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\d+$").unwrap());
+static LIST: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\[.*\]$").unwrap());
+
+pub fn parse_packet(s: &str) -> Result<Packet, ParseError> {
+    if NUMBER.is_match(s) {
+        return s
+            .parse()
+            .map(Packet::Number)
+            .map_err(|e| ParseError::new(format!("{s} is not a valid number: {e}")));
+    }
+
+    if s == "[]" {
+        return Ok(Packet::List(Vec::new()));
+    }
+
+    if LIST.is_match(s) {
+        return parse_list(s);
+    }
+
+    Err(ParseError::new(format!("{s:?} is not a packet")))
+}
+
+// Pairs are separated by blank lines
+fn parse_packet_pairs(input: &str) -> Result<Vec<(Packet, Packet)>, ParseError> {
+    let mut pairs = Vec::new();
+    let mut pair = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+
+        let packet = parse_packet(line).map_err(|e| e.with_line(i + 1))?;
+        pair.push(packet);
+
+        if pair.len() == 2 {
+            let right = pair.pop().unwrap();
+            let left = pair.pop().unwrap();
+            pairs.push((left, right));
+        }
+    }
+
+    if !pair.is_empty() {
+        return Err(ParseError::new("The last packet has no pair"));
+    }
+
+    Ok(pairs)
+}
+
+fn sum_ordered_pairs(packet_pairs: &[(Packet, Packet)]) -> usize {
+    packet_pairs
+        .iter()
+        .map(|(p1, p2)| compare_packets(p1, p2))
+        .enumerate()
+        .inspect(|&(i, ordered)| trace!(pair = i + 1, ordered))
+        .filter(|&(_i, b)| b)
+        .map(|(i, _b)| i + 1)
+        .sum::<usize>()
+}
+
+fn divider_packets() -> [Packet; 2] {
+    [2, 6].map(|n| Packet::List(vec![Packet::List(vec![Packet::Number(n)])]))
+}
+
+// Only the two dividers that are added count, even if the packets already
+// have copies of them. Each goes before the packets that compare equal to it.
+fn find_decoder_key(packet_pairs: &[(Packet, Packet)]) -> usize {
+    let packets = packet_pairs.iter().flat_map(|(p1, p2)| [p1, p2]).collect::<Vec<&Packet>>();
+
+    divider_packets()
+        .iter()
+        .enumerate()
+        .map(|(i, divider)| packets.iter().filter(|&&p| p < divider).count() + i + 1)
+        .product()
+}
+
+pub struct DistressSignal;
+
+impl Solution for DistressSignal {
+    type Input = Vec<(Packet, Packet)>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Vec<(Packet, Packet)>, ParseError> {
+        parse_packet_pairs(input)
+    }
+
+    // Sum of the indices of the pairs in the right order
+    fn part_a(packet_pairs: &Vec<(Packet, Packet)>) -> usize {
+        sum_ordered_pairs(packet_pairs)
+    }
+
+    // Decoder key
+    fn part_b(packet_pairs: &Vec<(Packet, Packet)>) -> usize {
+        find_decoder_key(packet_pairs)
+    }
+}
+
+// Deeper lists get shorter, so packets stay a readable size
+fn random_list(rng: &mut Rng, depth: usize) -> Packet {
+    let len = rng.range(0..(6 - depth as i64).max(1));
+
+    Packet::List(
+        (0..len)
+            .map(|_| match depth < 4 && rng.chance(0.3) {
+                true => random_list(rng, depth + 1),
+                false => Packet::Number(rng.range(0..11) as i32),
+            })
+            .collect(),
+    )
+}
+
+// Anything but the divider packets, which part B adds itself
+fn random_packet(rng: &mut Rng) -> Packet {
+    loop {
+        let packet = random_list(rng, 0);
+        if !divider_packets().contains(&packet) {
+            return packet;
+        }
+    }
+}
+
+// `size` pairs of packets
+impl Generate for DistressSignal {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| format!("{}\n{}", random_packet(rng), random_packet(rng)))
+            .collect::<Vec<String>>()
+            .join("\n\n")
+    }
+}
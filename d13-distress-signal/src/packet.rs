@@ -9,14 +9,35 @@ mod test {
         assert_eq!(compare_number_packets(&0, &1), CorrectOrder);
         assert_eq!(compare_number_packets(&1, &0), IncorrectOrder);
     }
+
+    #[test]
+    fn test_number_and_list_compare_equal() {
+        use super::Packet::{List, Number};
+        use std::cmp::Ordering;
+
+        let list = List(vec![Number(2)]);
+        let nested = List(vec![List(vec![Number(2)])]);
+        assert_eq!(list.cmp(&nested), Ordering::Equal);
+        assert_ne!(list, nested);
+    }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Packet {
     Number(i32),
     List(Vec<Packet>),
 }
 
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Packets are ordered as the puzzle compares them, which wraps a number in a
+// list when it meets one. That makes [2] and [[2]] compare Equal even though
+// they aren't ==, so sorting keeps such packets in their original order, and
+// sets or maps keyed on the order would take them to be the same packet.
 impl Ord for Packet {
     fn cmp(&self, other: &Self) -> Ordering {
         use PacketComparison::*;
@@ -29,8 +50,8 @@ impl Ord for Packet {
     }
 }
 
-impl ToString for Packet {
-    fn to_string(&self) -> String {
+impl Display for Packet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut s = String::new();
 
         match self {
@@ -38,13 +59,13 @@ impl ToString for Packet {
             Packet::List(l) => {
                 s.push('[');
 
-                l.into_iter().map(|p| p.to_string()).for_each(|ps| {
+                l.iter().map(|p| p.to_string()).for_each(|ps| {
                     s.push_str(&ps);
                     s.push(',');
                 });
 
 
-                if l.len() >= 1 {
+                if !l.is_empty() {
                     s.pop();
                 }
 
@@ -52,7 +73,7 @@ impl ToString for Packet {
             }
         }
 
-        s
+        s.fmt(f)
     }
 }
 
@@ -67,21 +88,17 @@ enum PacketComparison {
 }
 
 pub fn compare_packets(left: &Packet, right: &Packet) -> bool {
-    if let PacketComparison::IncorrectOrder = is_in_order(left, right) {
-        return false;
-    } else {
-        return true;
-    }
+    !matches!(is_in_order(left, right), PacketComparison::IncorrectOrder)
 }
 
 fn compare_number_packets(i: &i32, j: &i32) -> PacketComparison {
     use PacketComparison::*;
     if i == j {
-        return KeepChecking;
+        KeepChecking
     } else if i < j {
-        return CorrectOrder;
+        CorrectOrder
     } else {
-        return IncorrectOrder;
+        IncorrectOrder
     }
 }
 
@@ -90,12 +107,12 @@ fn is_in_order(left: &Packet, right: &Packet) -> PacketComparison {
     match (left, right) {
         (Number(l), Number(r)) => compare_number_packets(l, r),
         (List(l), List(r)) => is_lists_in_order(l, r),
-        (List(ll), Number(rn)) => is_lists_in_order(ll, &vec![Number(*rn)]),
-        (Number(ln), List(rl)) => is_lists_in_order(&vec![Number(*ln)], rl),
+        (List(ll), Number(rn)) => is_lists_in_order(ll, &[Number(*rn)]),
+        (Number(ln), List(rl)) => is_lists_in_order(&[Number(*ln)], rl),
     }
 }
 
-fn is_lists_in_order(left: &Vec<Packet>, right: &Vec<Packet>) -> PacketComparison {
+fn is_lists_in_order(left: &[Packet], right: &[Packet]) -> PacketComparison {
     use PacketComparison::*;
 
     for i in 0..left.len() {
@@ -115,5 +132,52 @@ fn is_lists_in_order(left: &Vec<Packet>, right: &Vec<Packet>) -> PacketCompariso
         return KeepChecking;
     }
 
-    return CorrectOrder;
+    CorrectOrder
+}
+
+#[cfg(test)]
+mod properties {
+    use std::cmp::Ordering;
+
+    use proptest::prelude::*;
+
+    use super::Packet;
+    use crate::parse_packet;
+
+    fn packet() -> impl Strategy<Value = Packet> {
+        (0..11i32)
+            .prop_map(Packet::Number)
+            .prop_recursive(4, 32, 5, |inner| prop::collection::vec(inner, 0..5).prop_map(Packet::List))
+    }
+
+    // Packets in the input are always lists
+    fn list_packet() -> impl Strategy<Value = Packet> {
+        prop::collection::vec(packet(), 0..5).prop_map(Packet::List)
+    }
+
+    proptest! {
+        #[test]
+        fn ordering_is_antisymmetric(a in packet(), b in packet()) {
+            prop_assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+        }
+
+        #[test]
+        fn ordering_is_transitive(a in packet(), b in packet(), c in packet()) {
+            for (x, y, z) in [(&a, &b, &c), (&a, &c, &b), (&b, &a, &c), (&b, &c, &a), (&c, &a, &b), (&c, &b, &a)] {
+                prop_assert!(!(x <= y && y <= z) || x <= z, "{} <= {} <= {}", x, y, z);
+            }
+        }
+
+        #[test]
+        fn equal_packets_compare_equal(a in packet()) {
+            prop_assert_eq!(a.cmp(&a.clone()), Ordering::Equal);
+        }
+
+        #[test]
+        fn to_string_round_trips(a in list_packet()) {
+            let parsed = parse_packet(&a.to_string()).unwrap();
+            prop_assert_eq!(&parsed, &a);
+            prop_assert_eq!(parsed.cmp(&a), Ordering::Equal);
+        }
+    }
 }
@@ -0,0 +1,356 @@
+use std::cmp::{min, max};
+
+use aoc_common::{
+    render::{Cell, Frame, Recorder, Render, Visualize},
+    sparse_grid, ParseError, Part, Solution, SparseGrid,
+};
+use split_iter::Splittable;
+use tracing::{debug, trace};
+
+#[test]
+fn test_rocks_out_of_reach() {
+    let error = RegolithReservoir::parse("0,2 -> 2000000000,2\n498,-1 -> 498,2").err().unwrap();
+    assert_eq!(error.message(), "Rock at 498,-1 is outside the cave, which goes from y=0 down to y=1000");
+    assert!(RegolithReservoir::parse("500,0 -> 500,2000000000").is_err());
+
+    // Only the part of a rock path that sand can reach is kept
+    let cave = RegolithReservoir::parse("0,2 -> 2000000000,2").unwrap();
+    assert_eq!(cave.rocks().len(), 9);
+    assert_eq!(RegolithReservoir::part_b(&cave), 4);
+}
+
+#[test]
+fn test_buried_spawner() {
+    let cave = RegolithReservoir::parse("499,1 -> 501,1").unwrap();
+
+    assert_eq!(RegolithReservoir::part_a(&cave), 1);
+}
+
+pub type Pos = (i32, i32);
+pub type Line = (Pos, Pos);
+
+#[derive(Clone, Copy)]
+enum Obstacle {
+    Air,
+    Rock,
+    Sand,
+}
+
+// Deeper caves would take part B far too much sand to fill
+pub const MAX_DEPTH: i32 = 1000;
+
+#[derive(Clone)]
+pub struct Cave {
+    sand_spawner: Pos,
+    // Rocks and sand at rest, anywhere else is air
+    obstacle: SparseGrid<Obstacle>,
+    // The columns sand can reach
+    left: i32,
+    right: i32,
+    void: i32,
+}
+
+impl Cave {
+    pub fn build(rock_paths: Vec<Line>) -> Result<Self, ParseError> {
+        // Rock paths only run straight across or straight down
+        if let Some(((x1, y1), (x2, y2))) = rock_paths.iter().find(|((x1, y1), (x2, y2))| x1 != x2 && y1 != y2) {
+            return Err(ParseError::new(format!("Rock path from {x1},{y1} to {x2},{y2} is diagonal")));
+        }
+        if let Some((x, y)) = rock_paths.iter().flat_map(|&(a, b)| [a, b]).find(|&(_, y)| !(0..=MAX_DEPTH).contains(&y)) {
+            return Err(ParseError::new(format!("Rock at {x},{y} is outside the cave, which goes from y=0 down to y={MAX_DEPTH}")));
+        }
+
+        let sand_spawner = (500,0);
+        let void = rock_paths.iter().flat_map(|&((_, y1), (_, y2))| [y1, y2]).max().unwrap_or(0);
+
+        // Sand moves at most one column sideways for every row it falls,
+        // and comes to rest on the floor right below the void at the latest,
+        // so rocks further to the side are left out
+        let depth = void + 2;
+        let left = sand_spawner.0 - depth;
+        let right = sand_spawner.0 + depth;
+
+        let (vertical, horizontal) = rock_paths.into_iter()
+            .split(|((_x1, y1), (_x2, y2))| y1 == y2);
+
+        let mut rocks = Vec::new();
+
+        for ((x1, y1), (x2, y2)) in horizontal {
+            assert_eq!(y1, y2);
+
+            for x in max(min(x1, x2), left)..=min(max(x1, x2), right) {
+                rocks.push((x, y1));
+            }
+        }
+
+        for ((x1, y1), (x2, y2)) in vertical.filter(|((x, _), _)| (left..=right).contains(x)) {
+            assert_eq!(x1, x2);
+            for y in min(y1, y2)..=max(y1, y2) {
+                rocks.push((x1, y));
+            }
+        }
+
+        Ok(Self {
+            sand_spawner,
+            obstacle: rocks.into_iter().map(|rock| (cell(rock), Obstacle::Rock)).collect(),
+            left,
+            right,
+            void,
+        })
+    }
+
+    pub fn sand_spawner(&self) -> Pos {
+        self.sand_spawner
+    }
+
+    // Every rock sand can reach as x, y, from the top left
+    pub fn rocks(&self) -> Vec<Pos> {
+        let mut rocks = self
+            .obstacle
+            .iter()
+            .filter(|(_, o)| matches!(o, Obstacle::Rock))
+            .map(|((row, col), _)| (col as i32, row as i32))
+            .collect::<Vec<Pos>>();
+        rocks.sort_by_key(|&(x, y)| (y, x));
+
+        rocks
+    }
+
+    fn place(&mut self, pos: Pos, o: Obstacle) {
+        self.obstacle.insert(cell(pos), o);
+    }
+
+    fn is_below_void(&self, (_, y): Pos) -> bool {
+        y >= self.void
+    }
+
+    pub fn tick_a(&mut self) -> bool {
+        // Rocks closed in below the spawner, so sand piled up to the top
+        // without ever reaching the void
+        if self.contains_obstacle(self.sand_spawner) {
+            return false;
+        }
+
+        let mut sand = self.sand_spawner;
+
+        let mut moving = true;
+        while moving {
+            if self.is_below_void(sand) {
+                return false;
+            }
+
+            let down = (sand.0, sand.1 + 1);
+            let left = (sand.0 - 1, sand.1 + 1);
+            let right = (sand.0 + 1, sand.1 + 1);
+
+            if !self.contains_obstacle(down) {
+                sand = down;
+            } else if !self.contains_obstacle(left) {
+                sand = left;
+            } else if !self.contains_obstacle(right) {
+                sand = right;
+            } else {
+                moving = false;
+            }
+        }
+
+        trace!(?sand, "came to rest");
+        self.place(sand, Obstacle::Sand);
+
+        true
+    }
+
+    pub fn tick_b(&mut self) -> bool {
+        let mut sand = self.sand_spawner;
+
+        let mut moving = true;
+        while moving {
+            if self.is_below_void((sand.0, sand.1)) {
+                moving = false;
+            }
+
+            let down = (sand.0, sand.1 + 1);
+            let left = (sand.0 - 1, sand.1 + 1);
+            let right = (sand.0 + 1, sand.1 + 1);
+
+            if !self.contains_obstacle(down) {
+                sand = down;
+            } else if !self.contains_obstacle(left) {
+                sand = left;
+            } else if !self.contains_obstacle(right) {
+                sand = right;
+            } else {
+                moving = false;
+            }
+        }
+
+        trace!(?sand, "came to rest");
+        self.place(sand, Obstacle::Sand);
+
+        sand != self.sand_spawner
+    }
+
+    fn contains_obstacle(&self, pos: Pos) -> bool {
+        !matches!(self.obsticale_at(pos), Obstacle::Air)
+    }
+
+    fn obsticale_at(&self, pos: Pos) -> Obstacle {
+        self.obstacle.get(cell(pos)).copied().unwrap_or(Obstacle::Air)
+    }
+}
+
+fn cell((x, y): Pos) -> sparse_grid::Pos {
+    (y as i64, x as i64)
+}
+
+// The whole area sand can reach, with the floor two rows below the lowest
+// rock
+impl Render for Cave {
+    fn render(&self) -> Frame {
+        let floor = self.void as usize + 2;
+
+        Frame::from_fn(floor + 1, (self.right - self.left + 1) as usize, |(row, col)| {
+            let pos = (col as i32 + self.left, row as i32);
+
+            if row == floor {
+                return Cell::new('V', [90, 70, 60]);
+            }
+
+            if pos == self.sand_spawner {
+                return Cell::new('+', [230, 60, 60]);
+            }
+
+            match self.obsticale_at(pos) {
+                Obstacle::Air => Cell::blank('.'),
+                Obstacle::Rock => Cell::new('#', [140, 140, 150]),
+                Obstacle::Sand => Cell::new('o', [230, 190, 90]),
+            }
+        })
+    }
+}
+
+pub fn parse_lines(input: &str) -> Result<Vec<Line>, ParseError> {
+    let mut pairs = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        let parse_pos = |s: &str| -> Result<Pos, ParseError> {
+            let err = || ParseError::at_token(i + 1, line, s, "Not a position like 498,4");
+            let (x, y) = s.split_once(',').ok_or_else(err)?;
+            Ok((x.parse::<i32>().map_err(|_| err())?, y.parse::<i32>().map_err(|_| err())?))
+        };
+
+        let positions = line
+            .split(" -> ")
+            .map(parse_pos)
+            .collect::<Result<Vec<Pos>, ParseError>>()?;
+
+        let i1 = positions.iter().cloned();
+        let mut i2 = positions.iter().cloned();
+        i2.next();
+
+        pairs.extend(i1.zip(i2));
+    }
+
+    Ok(pairs)
+}
+
+// Counts the sand pieces poured until `tick` reports that the cave is done,
+// including the piece that caused it to stop.
+fn pour_sand(cave: &Cave, tick: fn(&mut Cave) -> bool) -> usize {
+    let mut cave = cave.clone();
+
+    let mut sand_pieces = 0;
+
+    loop {
+        sand_pieces += 1;
+        if !tick(&mut cave) {
+            break;
+        }
+    }
+
+    debug!("after {sand_pieces} pieces of sand:\n{}", cave.render());
+
+    sand_pieces
+}
+
+pub struct RegolithReservoir;
+
+impl Solution for RegolithReservoir {
+    type Input = Cave;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Cave, ParseError> {
+        Cave::build(parse_lines(input)?)
+    }
+
+    // Sand at rest before it flows into the abyss
+    fn part_a(cave: &Cave) -> usize {
+        // The last piece fell into the void
+        pour_sand(cave, Cave::tick_a) - 1
+    }
+
+    // Sand at rest once the source is blocked
+    fn part_b(cave: &Cave) -> usize {
+        pour_sand(cave, Cave::tick_b)
+    }
+}
+
+impl Visualize for RegolithReservoir {
+    // One frame per piece of sand
+    fn frames(cave: &Cave, part: Part, every: usize) -> Vec<Frame> {
+        let tick = match part {
+            Part::A => Cave::tick_a,
+            Part::B => Cave::tick_b,
+        };
+
+        let mut cave = cave.clone();
+        let mut recorder = Recorder::new(every);
+
+        recorder.record(|| cave.render());
+        while tick(&mut cave) {
+            recorder.record(|| cave.render());
+        }
+
+        recorder.finish(|| cave.render())
+    }
+}
+
+#[cfg(test)]
+mod properties {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // Straight rock paths around the spawner
+    fn rock_paths() -> impl Strategy<Value = Vec<Line>> {
+        let path = (490..510, 1..12, 0..6, any::<bool>()).prop_map(|(x, y, len, across)| {
+            if across {
+                ((x, y), (x + len, y))
+            } else {
+                ((x, y), (x, y + len))
+            }
+        });
+
+        prop::collection::vec(path, 1..6)
+    }
+
+    // A single rock too far to the side for any sand to reach, only
+    // lowering the floor to `depth + 2`
+    fn deeper(paths: &[Line], depth: i32) -> Vec<Line> {
+        let rock = (480 - depth, depth);
+        paths.iter().copied().chain([(rock, rock)]).collect()
+    }
+
+    proptest! {
+        #[test]
+        fn sand_grows_with_floor_depth(paths in rock_paths(), extra in 1..8) {
+            let shallow = Cave::build(paths.clone()).unwrap();
+            let depth = shallow.void + extra;
+            let deep = Cave::build(deeper(&paths, depth)).unwrap();
+
+            prop_assert!(RegolithReservoir::part_b(&shallow) <= RegolithReservoir::part_b(&deep));
+            prop_assert_eq!(RegolithReservoir::part_a(&shallow), RegolithReservoir::part_a(&deep));
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use std::sync::LazyLock;
+
+use aoc_common::{ParseError, Solution};
+use beacon::Sensor;
+use regex::Regex;
+use tracing::debug;
+use util::tuning_frequency;
+
+use crate::zone::Zone;
+pub mod beacon;
+pub mod util;
+pub mod zone;
+
+#[test]
+fn test_solve_a_counts_covered_positions() {
+    // Positions a sensor covers, other than its beacon, rather than the ones
+    // it leaves uncovered
+    let zone = Zone::new(vec![Sensor::new((0, 0), (2, 0))]);
+
+    assert_eq!(solve_a(&zone, 0), 4);
+    assert_eq!(solve_a(&zone, 1), 3);
+    assert_eq!(solve_a(&zone, 3), 0);
+}
+
+#[test]
+fn test_example() {
+    let sensors = parse_sensors(include_str!("test-input.txt")).unwrap();
+    let report = Report::new(sensors, EXAMPLE_ROW, EXAMPLE_RANGE);
+
+    assert_eq!(BeaconExclusionZone::part_a(&report), 26);
+    assert_eq!(BeaconExclusionZone::part_b(&report), 56000011);
+}
+
+static SENSOR_LINE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^Sensor at x=(?P<sx>-?\d+), y=(?P<sy>-?\d+): closest beacon is at x=(?P<bx>-?\d+), y=(?P<by>-?\d+)$").unwrap()
+});
+
+pub fn parse_sensors(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    input.lines().enumerate().map(|(i, line)| parse_sensor(i + 1, line)).collect()
+}
+
+fn parse_sensor(line_number: usize, line: &str) -> Result<Sensor, ParseError> {
+    let err = |m: &str| ParseError::at_line(line_number, m);
+    let caps = SENSOR_LINE
+        .captures(line)
+        .ok_or_else(|| err("Expected Sensor at x=.., y=..: closest beacon is at x=.., y=.."))?;
+    let coord = |name: &str| caps[name].parse::<i32>().map_err(|e| err(&format!("{}: {e}", &caps[name])));
+
+    let sensor_pos = (coord("sx")?, coord("sy")?);
+    let beacon_pos = (coord("bx")?, coord("by")?);
+
+    Ok(Sensor::new(sensor_pos, beacon_pos))
+}
+
+// `can_contain_distress_beacon` holds where a sensor rules out a beacon, so
+// those are the positions counted
+pub fn solve_a(zone: &Zone, row: i32) -> i32 {
+    let min_x = zone.sensors().iter().map(|s| s.sensor_pos.0 - s.radius).min().unwrap_or(0);
+    let max_x = zone.sensors().iter().map(|s| s.sensor_pos.0 + s.radius).max().unwrap_or(-1);
+    debug!(row, min_x, max_x, "scanning row");
+
+    let mut i = 0;
+    for x in min_x..=max_x {
+        let p = (x, row);
+        if zone.can_contain_distress_beacon(p) {
+            i += 1
+        }
+    }
+
+    i
+}
+
+pub fn solve_b(zone: &Zone, range: i32) -> i64 {
+    tuning_frequency(zone.find_distress_beacon(range))
+}
+
+// The row and search area aren't part of the input. Real inputs ask about
+// row 2000000 and a 4000000x4000000 area, which is what `parse` assumes,
+// while the example asks about row 10 and a 20x20 area.
+pub const REAL_ROW: i32 = 2000000;
+pub const REAL_RANGE: i32 = 4000000;
+pub const EXAMPLE_ROW: i32 = 10;
+pub const EXAMPLE_RANGE: i32 = 20;
+
+// The sensor zone along with the row and search area the input asks about
+#[derive(Debug)]
+pub struct Report {
+    zone: Zone,
+    row: i32,
+    range: i32,
+}
+
+impl Report {
+    pub fn new(sensors: Vec<Sensor>, row: i32, range: i32) -> Self {
+        Report {
+            zone: Zone::new(sensors),
+            row,
+            range,
+        }
+    }
+
+    pub fn zone(&self) -> &Zone {
+        &self.zone
+    }
+
+    pub fn row(&self) -> i32 {
+        self.row
+    }
+
+    pub fn range(&self) -> i32 {
+        self.range
+    }
+}
+
+pub struct BeaconExclusionZone;
+
+impl Solution for BeaconExclusionZone {
+    type Input = Report;
+    type AnswerA = i32;
+    type AnswerB = i64;
+
+    fn parse(input: &str) -> Result<Report, ParseError> {
+        Ok(Report::new(parse_sensors(input)?, REAL_ROW, REAL_RANGE))
+    }
+
+    // Positions where a beacon cannot be present
+    fn part_a(report: &Report) -> i32 {
+        solve_a(&report.zone, report.row)
+    }
+
+    // Tuning frequency of the distress beacon
+    fn part_b(report: &Report) -> i64 {
+        solve_b(&report.zone, report.range)
+    }
+}
+
@@ -1,5 +1,7 @@
 use std::collections::HashSet;
 
+use tracing::debug;
+
 use crate::{
     beacon::{Sensor, Pos},
     util::valid_pos,
@@ -15,6 +17,10 @@ impl Zone {
         Self { sensors }
     }
 
+    pub fn sensors(&self) -> &[Sensor] {
+        &self.sensors
+    }
+
     pub fn is_within_zone(&self, p: Pos) -> bool {
         self.sensors.iter().any(|b| b.within_radius(p))
     }
@@ -42,17 +48,16 @@ impl Zone {
     // right outside the radii of one of the sensors
 
     pub fn find_distress_beacon(&self, range: i32) -> Pos {
-
-
         let boundaries = self
             .sensors
             .iter()
-            .map(Sensor::boundary_points)
-            .flatten()
+            .flat_map(Sensor::boundary_points)
             .collect::<HashSet<Pos>>();
+        debug!(candidates = boundaries.len(), "checking sensor boundaries");
 
         for pos in boundaries {
             if valid_pos(range, pos) && !self.is_within_zone(pos) {
+                debug!(?pos, "found the distress beacon");
                 return pos;
             }
         }
@@ -60,3 +65,42 @@ impl Zone {
         (-1, -1)
     }
 }
+
+#[cfg(test)]
+mod differential {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::util::manhattan_distance;
+
+    // Sensors covering every position in range but `hidden`, each reaching
+    // just short of it, the way puzzle inputs leave exactly one spot
+    fn zone_hiding(range: i32, hidden: Pos) -> Zone {
+        let mut sensors: Vec<Sensor> = Vec::new();
+
+        for y in 0..=range {
+            for x in 0..=range {
+                let p = (x, y);
+                if p != hidden && !sensors.iter().any(|s| s.within_radius(p)) {
+                    let radius = manhattan_distance(p, hidden) - 1;
+                    sensors.push(Sensor::new(p, (x + radius, y)));
+                }
+            }
+        }
+
+        Zone::new(sensors)
+    }
+
+    proptest! {
+        // A range of 0 needs no sensors, and then the fast search has no
+        // boundaries to look along
+        #[test]
+        fn fast_search_matches_slow_search(range in 1..40i32, x in 0..40i32, y in 0..40i32) {
+            let hidden = (x % (range + 1), y % (range + 1));
+            let zone = zone_hiding(range, hidden);
+
+            prop_assert_eq!(zone.find_distress_beacon_slow(range), hidden);
+            prop_assert_eq!(zone.find_distress_beacon(range), hidden);
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::{BTreeSet, HashMap};
+
+pub mod pressure;
+pub mod solution_a;
+pub mod solution_b;
+
+use aoc_common::{
+    generate::{Generate, Rng},
+    ParseError, Solution,
+};
+use itertools::Itertools;
+use regex::Regex;
+
+pub type ValveID = usize;
+
+use crate::{solution_a::solve_a, solution_b::solve_b};
+
+#[test]
+fn test_too_many_flow_valves() {
+    let names = (0..65).map(|i| format!("{}{}", (b'B' + i / 26) as char, (b'A' + i % 26) as char)).collect::<Vec<String>>();
+    let mut input = format!("Valve AA has flow rate=0; tunnels lead to valves {}\n", names.join(", "));
+    for name in &names {
+        input.push_str(&format!("Valve {name} has flow rate=1; tunnel leads to valve AA\n"));
+    }
+
+    assert_eq!(parse_valves(&input).unwrap_err().message(), "Only 64 valves can have a flow rate, not 65");
+}
+
+#[derive(Debug)]
+pub struct Valve {
+    pub id: usize,
+    pub name: String,
+    pub flow_rate: i32,
+    pub leads_to: Vec<ValveID>,
+}
+
+pub fn parse_valves(input: &str) -> Result<Vec<Valve>, ParseError> {
+    pub struct ParsedValve {
+        pub name: String,
+        pub flow_rate: i32,
+        pub leads_to: Vec<String>,
+    }
+
+    fn parse_valve(line_number: usize, line: &str) -> Result<ParsedValve, ParseError> {
+        let re = Regex::new(
+            r"^Valve (?P<name>[A-Z]{2}) has flow rate=(?P<flow_rate>\d+); tunnel(s)? lead(s)? to valve(s)? (?P<leads_to>([A-Z]{2}, )*[A-Z]{2})$"
+        ).unwrap();
+
+        let caps = re.captures(line).ok_or_else(|| {
+            ParseError::at_line(line_number, "Expected Valve XX has flow rate=n; tunnels lead to valves YY, ZZ")
+        })?;
+
+        let name = caps["name"].to_string();
+        let flow_rate = caps["flow_rate"]
+            .parse()
+            .map_err(|e| ParseError::at_line(line_number, format!("Invalid flow rate: {e}")))?;
+        let leads_to = caps["leads_to"]
+            .split(", ")
+            .map(ToString::to_string)
+            .collect();
+
+        Ok(ParsedValve {
+            name,
+            flow_rate,
+            leads_to,
+        })
+    }
+
+    fn resolve_valves(vs: Vec<ParsedValve>) -> Result<Vec<Valve>, ParseError> {
+        let name_to_id_resolver: HashMap<String, ValveID> =
+            vs.iter().enumerate().map(|(i, v)| (v.name.clone(), i)).collect();
+
+        let mut valves = Vec::new();
+
+        for (i, pv) in vs.into_iter().enumerate() {
+            let leads_to = pv
+                .leads_to
+                .iter()
+                .map(|vn| {
+                    name_to_id_resolver
+                        .get(vn)
+                        .copied()
+                        .ok_or_else(|| ParseError::at_line(i + 1, format!("Valve {vn} does not exist")))
+                })
+                .collect::<Result<Vec<ValveID>, ParseError>>()?;
+
+            let v = Valve {
+                id: name_to_id_resolver[&pv.name],
+                name: pv.name,
+                flow_rate: pv.flow_rate,
+                leads_to,
+            };
+
+            valves.push(v);
+        }
+
+        Ok(valves)
+    }
+
+    let parsed_valves = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_valve(i + 1, line))
+        .collect::<Result<Vec<ParsedValve>, ParseError>>()?;
+
+    if !parsed_valves.iter().map(|v| &v.name).all_unique() {
+        return Err(ParseError::new("Valve names must be unique"));
+    }
+    if !parsed_valves.iter().any(|v| v.name == "AA") {
+        return Err(ParseError::new("There is no valve AA to start from"));
+    }
+    // Part B keeps track of the opened valves in a u64
+    let flow_valves = parsed_valves.iter().filter(|v| v.flow_rate != 0).count();
+    if flow_valves > 64 {
+        return Err(ParseError::new(format!("Only 64 valves can have a flow rate, not {flow_valves}")));
+    }
+
+    resolve_valves(parsed_valves)
+}
+
+pub struct ProboscideaVolcanium;
+
+impl Solution for ProboscideaVolcanium {
+    type Input = Vec<Valve>;
+    type AnswerA = i32;
+    type AnswerB = i32;
+
+    fn parse(input: &str) -> Result<Vec<Valve>, ParseError> {
+        parse_valves(input)
+    }
+
+    fn part_a(valves: &Vec<Valve>) -> i32 {
+        solve_a(valves)
+    }
+
+    fn part_b(valves: &Vec<Valve>) -> i32 {
+        solve_b(valves)
+    }
+}
+
+// Part A tries every order of the valves that release pressure, keep it quick
+const MAX_GENERATED_FLOW_VALVES: usize = 7;
+
+// `size` valves, starting from AA, joined both ways by tunnels so that every
+// valve can reach every other
+impl Generate for ProboscideaVolcanium {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let count = size.clamp(2, 26 * 26);
+
+        let mut names = (0..26 * 26)
+            .map(|i| format!("{}{}", (b'A' + (i / 26) as u8) as char, (b'A' + (i % 26) as u8) as char))
+            .filter(|name| name != "AA")
+            .collect::<Vec<String>>();
+        rng.shuffle(&mut names);
+        names.insert(0, "AA".to_string());
+        names.truncate(count);
+
+        let mut tunnels = vec![BTreeSet::new(); count];
+        let mut connect = |a: usize, b: usize| {
+            if a != b {
+                tunnels[a].insert(b);
+                tunnels[b].insert(a);
+            }
+        };
+
+        for valve in 1..count {
+            connect(valve, rng.below(valve));
+        }
+        for _ in 0..count / 4 {
+            connect(rng.below(count), rng.below(count));
+        }
+
+        let mut flow_valves = 0;
+        (0..count)
+            .map(|valve| {
+                let flow_rate = match valve != 0 && flow_valves < MAX_GENERATED_FLOW_VALVES && rng.chance(0.4) {
+                    true => {
+                        flow_valves += 1;
+                        rng.range(1..26)
+                    }
+                    false => 0,
+                };
+
+                let leads_to = tunnels[valve].iter().map(|&v| names[v].as_str()).join(", ");
+                match tunnels[valve].len() {
+                    1 => format!("Valve {} has flow rate={flow_rate}; tunnel leads to valve {leads_to}", names[valve]),
+                    _ => format!("Valve {} has flow rate={flow_rate}; tunnels lead to valves {leads_to}", names[valve]),
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
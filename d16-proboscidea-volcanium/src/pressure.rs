@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use tracing::debug;
+
+use crate::{
+    solution_a::{compute_dequeue_weight, compute_weights},
+    Valve, ValveID,
+};
+
+// Every valve that releases pressure, with the time it takes to get between
+// them and from AA, the valve you start at
+struct Search {
+    flow_rates: Vec<i32>,
+    // Between valves, with the distances from AA in the last row
+    distances: Vec<Vec<i32>>,
+    // The most pressure each set of opened valves has released so far
+    best: HashMap<u64, i32>,
+}
+
+impl Search {
+    fn new(valves: &[Valve]) -> Self {
+        let weights = compute_weights(valves);
+        let start = valves.iter().find(|v| v.name == "AA").map(|v| v.id).expect("Parsing checks for valve AA");
+        let flow_valves = valves.iter().filter(|v| v.flow_rate != 0).collect::<Vec<&Valve>>();
+
+        let distance = |from: ValveID, to: ValveID| match from == to {
+            true => 0,
+            false => weights.get(&(from, to)).copied().unwrap_or_else(|| compute_dequeue_weight(from, to, valves, &weights)),
+        };
+        let distances = flow_valves
+            .iter()
+            .map(|v| v.id)
+            .chain([start])
+            .map(|from| flow_valves.iter().map(|to| distance(from, to.id)).collect())
+            .collect();
+
+        Search {
+            flow_rates: flow_valves.iter().map(|v| v.flow_rate).collect(),
+            distances,
+            best: HashMap::new(),
+        }
+    }
+
+    fn visit(&mut self, from: usize, minutes_left: i32, opened: u64, pressure: i32) {
+        let best = self.best.entry(opened).or_insert(0);
+        *best = (*best).max(pressure);
+
+        for next in 0..self.flow_rates.len() {
+            // Getting there and opening it leaves this long for it to flow
+            let minutes_left = minutes_left - self.distances[from][next] - 1;
+
+            if opened & 1 << next == 0 && minutes_left > 0 {
+                self.visit(next, minutes_left, opened | 1 << next, pressure + minutes_left * self.flow_rates[next]);
+            }
+        }
+    }
+}
+
+// The most pressure that opening each set of valves can release in `minutes`,
+// starting from AA. Sets are bitmasks over the valves with a flow rate, in
+// input order, and only the sets that can all be opened in time are kept.
+// Parsing makes sure there are few enough valves for a bitmask.
+pub fn best_pressures(valves: &[Valve], minutes: i32) -> HashMap<u64, i32> {
+    let mut search = Search::new(valves);
+    let start = search.flow_rates.len();
+
+    search.visit(start, minutes, 0, 0);
+    debug!(sets = search.best.len(), "found the best pressure for each set of valves");
+
+    search.best
+}
@@ -2,16 +2,17 @@ use std::collections::{HashMap, HashSet};
 
 use itertools::Itertools;
 use priority_queue::DoublePriorityQueue;
+use tracing::{debug, trace};
 
 use crate::{Valve, ValveID};
 
 // Time it takes to move from one valve to the next.
 // Moving from one valve to the another adjacent valve takes 1 minute.
-fn compute_weight(
+pub fn compute_weight(
     from: ValveID,
     to: ValveID,
-    valves: &Vec<Valve>,
-    computed_weights: &HashMap<(ValveID, ValveID), i32>,
+    valves: &[Valve],
+    _computed_weights: &HashMap<(ValveID, ValveID), i32>,
 ) -> i32 {
     let from_valve = &valves[from];
 
@@ -48,10 +49,10 @@ fn compute_weight(
     panic!("Cannot reach a node!");
 }
 
-fn compute_dequeue_weight(
+pub fn compute_dequeue_weight(
     from: ValveID,
     to: ValveID,
-    valves: &Vec<Valve>,
+    valves: &[Valve],
     computed_weights: &HashMap<(ValveID, ValveID), i32>,
 ) -> i32 {
     let from_valve = &valves[from];
@@ -84,7 +85,7 @@ fn compute_dequeue_weight(
 
 // NOTE: Refactored to use a number to index a vec rather than a string into a hashmap
 // to avoid cloning the reference over and over
-fn compute_weights(valves: &Vec<Valve>) -> HashMap<(ValveID, ValveID), i32> {
+pub fn compute_weights(valves: &[Valve]) -> HashMap<(ValveID, ValveID), i32> {
     let perms = valves
         .iter()
         .filter(|v| v.flow_rate != 0)
@@ -102,17 +103,16 @@ fn compute_weights(valves: &Vec<Valve>) -> HashMap<(ValveID, ValveID), i32> {
     weights
 }
 
-pub fn solve_a(valves: Vec<Valve>) -> i32 {
-    valves.iter().for_each(|v| println!("{v:?}"));
+pub fn solve_a(valves: &[Valve]) -> i32 {
     let minutes_left = 30;
 
-    let mut weights = compute_weights(&valves);
+    let weights = compute_weights(valves);
 
     let flow_valves = valves
         .iter()
         .enumerate()
-        .filter(|(i, v)| v.flow_rate != 0)
-        .map(|(i, v)| i).collect_vec();
+        .filter(|(_, v)| v.flow_rate != 0)
+        .map(|(i, _)| i).collect_vec();
 
 
     let mut max = 0;
@@ -120,14 +120,17 @@ pub fn solve_a(valves: Vec<Valve>) -> i32 {
     let get_flow = |id: ValveID| valves[id].flow_rate;
 
     // Bug somewhere, but I can't find it ;/
-    for mut path in flow_valves.iter().copied().permutations(flow_valves.len() - 1) {
-        let mut previous_valve_id = path.pop().unwrap();
+    debug!(flow_valves = flow_valves.len(), "trying every order of the valves");
+
+    for mut path in flow_valves.iter().copied().permutations(flow_valves.len().saturating_sub(1)) {
+        trace!(?path);
+        let Some(mut previous_valve_id) = path.pop() else {
+            continue;
+        };
         let mut t = minutes_left - 2;
         let mut flow = t * get_flow(previous_valve_id);
 
-        while !path.is_empty() {
-            let current_valve_id = path.pop().unwrap();
-
+        while let Some(current_valve_id) = path.pop() {
             let time_between_vents = weights[&(previous_valve_id, current_valve_id)];
 
             t -= time_between_vents + 1;
@@ -140,8 +143,59 @@ pub fn solve_a(valves: Vec<Valve>) -> i32 {
             previous_valve_id = current_valve_id;
         }
 
+        trace!(flow);
         max = max.max(flow);
     }
 
     max
 }
+
+#[cfg(test)]
+mod differential {
+    use proptest::{prelude::*, sample::Index};
+
+    use super::*;
+
+    // Connected valves, tunnels go both ways
+    fn valves() -> impl Strategy<Value = Vec<Valve>> {
+        (2..9usize)
+            .prop_flat_map(|n| {
+                (
+                    prop::collection::vec(any::<Index>(), n - 1),
+                    prop::collection::vec((0..n, 0..n), 0..n),
+                    prop::collection::vec(0..3i32, n),
+                )
+            })
+            .prop_map(|(parents, extra, flow_rates)| {
+                let mut valves = flow_rates
+                    .into_iter()
+                    .enumerate()
+                    .map(|(id, flow_rate)| Valve {
+                        id,
+                        name: format!("V{id}"),
+                        flow_rate,
+                        leads_to: Vec::new(),
+                    })
+                    .collect::<Vec<Valve>>();
+
+                let tree = parents.iter().enumerate().map(|(i, parent)| (i + 1, parent.index(i + 1)));
+                for (a, b) in tree.chain(extra) {
+                    if a != b && !valves[a].leads_to.contains(&b) {
+                        valves[a].leads_to.push(b);
+                        valves[b].leads_to.push(a);
+                    }
+                }
+
+                valves
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn dequeue_weights_match_breadth_first_weights(valves in valves()) {
+            for ((from, to), weight) in compute_weights(&valves) {
+                prop_assert_eq!(weight, compute_weight(from, to, &valves, &HashMap::new()), "from {} to {}", from, to);
+            }
+        }
+    }
+}
@@ -1,5 +1,35 @@
-use crate::Valve;
+use itertools::Itertools;
 
-pub fn solve_b(_valve_map: Vec<Valve>) -> i32 {
-    unimplemented!()
+use crate::{pressure::best_pressures, Valve};
+
+#[test]
+fn test_example() {
+    let valves = crate::parse_valves(include_str!("test-input.txt")).unwrap();
+
+    assert_eq!(solve_b(&valves), 1707);
+}
+
+// You and an elephant each open a different set of valves in the 26 minutes
+// left after teaching it how
+pub fn solve_b(valves: &[Valve]) -> i32 {
+    let best = best_pressures(valves, 26)
+        .into_iter()
+        .sorted_by_key(|&(_, pressure)| -pressure)
+        .collect::<Vec<(u64, i32)>>();
+
+    // Pairs are tried from the most pressure down, so each search can stop
+    // once it can't beat the best pair found
+    let mut max = 0;
+    for (i, &(yours, your_pressure)) in best.iter().enumerate() {
+        for &(elephants, elephant_pressure) in &best[i..] {
+            if your_pressure + elephant_pressure <= max {
+                break;
+            }
+            if yours & elephants == 0 {
+                max = your_pressure + elephant_pressure;
+            }
+        }
+    }
+
+    max
 }
@@ -0,0 +1,220 @@
+use std::fmt::{self, Display};
+
+use crate::rules::{Outcome, Shape, ROCK_PAPER_SCISSORS};
+
+#[test]
+fn test_ciphers() {
+    let ciphers = Cipher::all();
+
+    assert_eq!(ciphers.len(), 72);
+    assert!(ciphers.iter().enumerate().all(|(i, a)| ciphers[i + 1..].iter().all(|b| a != b)));
+    assert_eq!(ciphers[0].to_string(), "A=Rock B=Paper C=Scissors X=Rock Y=Paper Z=Scissors");
+    assert!(ciphers.contains(&Cipher::outcomes()));
+    assert_eq!(Cipher::outcomes().to_string(), "A=Rock B=Paper C=Scissors X=loss Y=draw Z=win");
+}
+
+#[test]
+fn test_decrypt() {
+    let [rock, _, scissors] = shapes();
+
+    assert_eq!(Letter::ALL.map(Shape::from), shapes());
+    assert_eq!(Cipher::all()[0].decrypt((Letter::First, Letter::Third)), (rock, scissors));
+    assert_eq!(Cipher::outcomes().decrypt((Letter::First, Letter::First)), (rock, scissors));
+    assert_eq!(Cipher::outcomes().decrypt((Letter::Third, Letter::Third)), (scissors, rock));
+    assert_eq!(Cipher::outcomes().outcome(Letter::Second), Some(Outcome::Draw));
+    assert_eq!(Cipher::all()[0].outcome(Letter::Second), None);
+}
+
+#[test]
+fn test_tally() {
+    use Letter::{First, Second, Third};
+
+    let moves = [(First, Second), (Second, First), (Third, Third), (Third, Third)];
+    let tally = moves.into_iter().collect::<Tally>();
+
+    assert_eq!(tally, Tally([[0, 1, 0], [1, 0, 0], [0, 0, 2]]));
+    assert_eq!(tally.score(&Cipher::all()[0]), crate::get_total_score(moves.to_vec()) as i64);
+    assert_eq!(tally.score(&Cipher::outcomes()), 4 + 1 + 7 + 7);
+}
+
+#[test]
+fn test_score_ciphers() {
+    let moves = crate::read_encrypted_message_file(aoc_common::input_path!("src/strategy.txt"))
+        .and_then(crate::decrypt_all_moves)
+        .unwrap();
+    let scores = score_ciphers(&moves.iter().copied().collect());
+
+    let score = |cipher: &Cipher| scores.iter().find(|(c, _)| c == cipher).unwrap().1;
+    assert_eq!(score(&Cipher::all()[0]), 14264);
+    assert_eq!(score(&Cipher::outcomes()), 12382);
+
+    let (highest, lowest) = (highest(&scores).unwrap().1, lowest(&scores).unwrap().1);
+    assert!(scores.iter().all(|&(_, score)| (lowest..=highest).contains(&score)));
+    assert!(highest > 14264 && lowest < 12382);
+}
+
+// A letter of the strategy guide, by its place in its column: A, B or C for
+// the opponent and X, Y or Z for you
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Letter {
+    First,
+    Second,
+    Third,
+}
+
+impl Letter {
+    pub const ALL: [Letter; 3] = [Letter::First, Letter::Second, Letter::Third];
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+// As in part A, the nth letter of either column is the nth shape of Rock
+// Paper Scissors
+impl From<Letter> for Shape {
+    fn from(letter: Letter) -> Shape {
+        shapes()[letter.index()]
+    }
+}
+
+// What X, Y and Z in the third column stand for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    Shapes([Shape; 3]),
+    Outcomes([Outcome; 3]),
+}
+
+// A way to read a strategy guide, mapping A, B and C to the opponent's shapes
+// and X, Y and Z to either your shapes or the outcomes to aim for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cipher {
+    pub opponent: [Shape; 3],
+    pub yours: Column,
+}
+
+// Every order of three things
+const ORDERS: [[usize; 3]; 6] = [[0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]];
+
+fn shapes() -> [Shape; 3] {
+    let shapes = ROCK_PAPER_SCISSORS.shapes().collect::<Vec<Shape>>();
+    shapes.try_into().expect("Rock Paper Scissors has three shapes")
+}
+
+impl Cipher {
+    // Starts with the cipher of part A, where every column is in shape order
+    pub fn all() -> Vec<Cipher> {
+        let shapes = shapes();
+        let outcomes = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+
+        let mut ciphers = Vec::new();
+        for opponent in ORDERS {
+            let opponent = opponent.map(|i| shapes[i]);
+
+            for yours in ORDERS {
+                ciphers.push(Cipher {
+                    opponent,
+                    yours: Column::Shapes(yours.map(|i| shapes[i])),
+                });
+            }
+            for yours in ORDERS {
+                ciphers.push(Cipher {
+                    opponent,
+                    yours: Column::Outcomes(yours.map(|i| outcomes[i])),
+                });
+            }
+        }
+
+        ciphers
+    }
+
+    // The cipher of part B, as in `decrypt_moveset2`
+    pub fn outcomes() -> Cipher {
+        Cipher {
+            opponent: shapes(),
+            yours: Column::Outcomes([Outcome::Loss, Outcome::Draw, Outcome::Win]),
+        }
+    }
+
+    // The outcome `your_letter` stands for, when the third column is outcomes
+    pub fn outcome(&self, your_letter: Letter) -> Option<Outcome> {
+        match self.yours {
+            Column::Shapes(_) => None,
+            Column::Outcomes(outcomes) => Some(outcomes[your_letter.index()]),
+        }
+    }
+
+    // The shapes played in a round, from its letters
+    pub fn decrypt(&self, (opponent_letter, your_letter): (Letter, Letter)) -> (Shape, Shape) {
+        let opponent_shape = self.opponent[opponent_letter.index()];
+        let your_shape = match self.yours {
+            Column::Shapes(shapes) => shapes[your_letter.index()],
+            Column::Outcomes(outcomes) => ROCK_PAPER_SCISSORS
+                .shape_for(opponent_shape, outcomes[your_letter.index()])
+                .expect("Every outcome has a shape in Rock Paper Scissors"),
+        };
+
+        (opponent_shape, your_shape)
+    }
+}
+
+impl Display for Cipher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (letter, shape) in ['A', 'B', 'C'].iter().zip(self.opponent) {
+            write!(f, "{letter}={} ", ROCK_PAPER_SCISSORS.name(shape))?;
+        }
+
+        let yours = match self.yours {
+            Column::Shapes(shapes) => shapes.map(|shape| ROCK_PAPER_SCISSORS.name(shape).to_string()),
+            Column::Outcomes(outcomes) => outcomes.map(|outcome| outcome.to_string()),
+        };
+        write!(f, "X={} Y={} Z={}", yours[0], yours[1], yours[2])
+    }
+}
+
+// How often each pair of letters comes up in a guide, which is all it takes
+// to score the guide under any cipher
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Tally([[u64; 3]; 3]);
+
+impl Tally {
+    pub fn add(&mut self, (opponent_letter, your_letter): (Letter, Letter)) {
+        self.0[opponent_letter.index()][your_letter.index()] += 1;
+    }
+
+    pub fn score(&self, cipher: &Cipher) -> i64 {
+        Letter::ALL
+            .into_iter()
+            .flat_map(|a| Letter::ALL.map(|b| (a, b)))
+            .map(|(a, b)| self.0[a.index()][b.index()] as i64 * ROCK_PAPER_SCISSORS.score(cipher.decrypt((a, b))) as i64)
+            .sum()
+    }
+}
+
+impl FromIterator<(Letter, Letter)> for Tally {
+    fn from_iter<T: IntoIterator<Item = (Letter, Letter)>>(moves: T) -> Self {
+        let mut tally = Tally::default();
+        for mv in moves {
+            tally.add(mv);
+        }
+        tally
+    }
+}
+
+// Your total score under every cipher, in the order of `Cipher::all`
+pub fn score_ciphers(tally: &Tally) -> Vec<(Cipher, i64)> {
+    Cipher::all()
+        .into_iter()
+        .map(|cipher| (cipher, tally.score(&cipher)))
+        .collect()
+}
+
+// Ties go to the cipher listed first
+pub fn highest(scores: &[(Cipher, i64)]) -> Option<&(Cipher, i64)> {
+    scores.iter().rev().max_by_key(|(_, score)| score)
+}
+
+// Ties go to the cipher listed first
+pub fn lowest(scores: &[(Cipher, i64)]) -> Option<&(Cipher, i64)> {
+    scores.iter().min_by_key(|(_, score)| score)
+}
@@ -0,0 +1,178 @@
+pub mod cipher;
+pub mod rules;
+
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path,
+};
+
+use aoc_common::{
+    generate::{Generate, Rng},
+    stream::{LineParser, Stream},
+    ParseError, Solution,
+};
+
+use crate::{
+    cipher::{Cipher, Letter},
+    rules::{Shape, ROCK_PAPER_SCISSORS},
+};
+
+#[test]
+fn test_small_score() {
+    let score = read_encrypted_message_file(aoc_common::input_path!("src/small-strategy.txt"))
+        .and_then(decrypt_all_moves)
+        .map(get_total_score)
+        .unwrap();
+    assert_eq!(score, 15);
+}
+
+#[test]
+fn test_score() {
+    let score = read_encrypted_message_file(aoc_common::input_path!("src/strategy.txt"))
+        .and_then(decrypt_all_moves)
+        .map(get_total_score)
+        .unwrap();
+    assert_eq!(score, 14264);
+}
+
+#[test]
+fn test_score2() {
+    let moves: Vec<(Shape, Shape)> = read_encrypted_message_file(aoc_common::input_path!("src/strategy.txt"))
+        .and_then(decrypt_all_moves)
+        .unwrap()
+        .into_iter()
+        .map(decrypt_moveset2)
+        .collect();
+
+    assert_eq!(get_total_score(moves), 12382);
+}
+
+fn get_moveset_score(moveset: (Shape, Shape)) -> i32 {
+    ROCK_PAPER_SCISSORS.score(moveset)
+}
+
+pub fn read_encrypted_message_file(filename: impl AsRef<Path>) -> Result<Vec<(String, String)>, ParseError> {
+    let filename = filename.as_ref().display().to_string();
+    let file = File::open(&filename).map_err(|e| ParseError::new(e.to_string()).with_file(&filename))?;
+    read_encrypted_message(BufReader::new(file)).map_err(|e| e.with_file(filename))
+}
+
+pub fn read_encrypted_message(reader: impl BufRead) -> Result<Vec<(String, String)>, ParseError> {
+    let mut predicted_moves: Vec<(String, String)> = Vec::new();
+
+    for (i, line) in reader.lines().enumerate() {
+        let line_number = i + 1;
+        let x = line.map_err(|e| ParseError::at_line(line_number, e.to_string()))?;
+
+        predicted_moves.push(read_predicted_move(line_number, &x)?);
+    }
+
+    Ok(predicted_moves)
+}
+
+fn read_predicted_move(line_number: usize, x: &str) -> Result<(String, String), ParseError> {
+    if x.chars().count() != 3 || x.chars().nth(1) != Some(' ') {
+        return Err(ParseError::in_line(line_number, x, "Expected two shapes separated by a space"));
+    }
+
+    let (opponent_shape, your_shape) = x.split_at(1);
+
+    // Remove whitespace
+    let a = opponent_shape.trim();
+    let b = your_shape.trim();
+
+    Ok((a.to_string(), b.to_string()))
+}
+
+// The letters of a move, from a line of the strategy guide
+pub fn parse_moveset(line_number: usize, line: &str) -> Result<(Letter, Letter), ParseError> {
+    read_predicted_move(line_number, line).and_then(|mv| decrypt_moveset(mv).map_err(|e| e.with_line(line_number)))
+}
+
+// Letters sit in the first and third column of a line. A, B and C, like X,
+// Y and Z, stand for the shapes of Rock Paper Scissors in turn, as
+// `Shape::from` reads them.
+pub fn decrypt_moveset((opponent_shape, your_shape): (String, String)) -> Result<(Letter, Letter), ParseError> {
+    let errfn = |s: &str, column: usize, e: &str| Err(ParseError::new(format!("{s}: {e}")).with_span(column, 1));
+    let decrypt = |s: &str, letters: &str| {
+        letters
+            .find(s)
+            .filter(|_| s.len() == 1)
+            .map(|i| Letter::ALL[i])
+    };
+
+    let Some(a) = decrypt(&opponent_shape, "ABC") else {
+        return errfn(&opponent_shape, 1, "Opponent Shape was not A,B, or C!");
+    };
+
+    let Some(b) = decrypt(&your_shape, "XYZ") else {
+        return errfn(&your_shape, 3, "Your Shape was not X,Y, or Z!");
+    };
+
+    Ok((a, b))
+}
+
+// The third column is really the outcome to aim for: X, Y and Z are to lose,
+// draw and win.
+pub fn decrypt_moveset2(moveset: (Letter, Letter)) -> (Shape, Shape) {
+    Cipher::outcomes().decrypt(moveset)
+}
+
+// Moves are in line order, one per line
+pub fn decrypt_all_moves(moves: Vec<(String, String)>) -> Result<Vec<(Letter, Letter)>, ParseError> {
+    moves
+        .into_iter()
+        .enumerate()
+        .map(|(i, mv)| decrypt_moveset(mv).map_err(|e| e.with_line(i + 1)))
+        .collect()
+}
+
+// Takes shapes, or letters read as in part A
+pub fn get_total_score<S: Into<Shape>>(moves: Vec<(S, S)>) -> i32 {
+    moves.into_iter().map(|(a, b)| get_moveset_score((a.into(), b.into()))).sum::<i32>()
+}
+
+pub struct RockPaperScissors;
+
+impl Solution for RockPaperScissors {
+    type Input = Vec<(Letter, Letter)>;
+    type AnswerA = i32;
+    type AnswerB = i32;
+
+    fn parse(input: &str) -> Result<Vec<(Letter, Letter)>, ParseError> {
+        read_encrypted_message(input.as_bytes()).and_then(decrypt_all_moves)
+    }
+
+    fn part_a(moves: &Vec<(Letter, Letter)>) -> i32 {
+        get_total_score(moves.clone())
+    }
+
+    fn part_b(moves: &Vec<(Letter, Letter)>) -> i32 {
+        get_total_score(moves.iter().map(|&mv| decrypt_moveset2(mv)).collect())
+    }
+}
+
+impl Stream for RockPaperScissors {
+    fn stream_a(reader: impl BufRead) -> Result<i32, ParseError> {
+        LineParser::new(reader.lines(), parse_moveset)
+            .map(|mv| mv.map(|(a, b)| get_moveset_score((a.into(), b.into()))))
+            .sum()
+    }
+
+    fn stream_b(reader: impl BufRead) -> Result<i32, ParseError> {
+        LineParser::new(reader.lines(), parse_moveset)
+            .map(|mv| mv.map(|mv| get_moveset_score(decrypt_moveset2(mv))))
+            .sum()
+    }
+}
+
+// `size` rounds of the strategy guide
+impl Generate for RockPaperScissors {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| format!("{} {}", rng.pick(&['A', 'B', 'C']), rng.pick(&['X', 'Y', 'Z'])))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,274 @@
+use std::{
+    fmt::{self, Display},
+    sync::LazyLock,
+};
+
+use aoc_common::ParseError;
+
+pub static ROCK_PAPER_SCISSORS: LazyLock<Rules> =
+    LazyLock::new(|| Rules::from_table(include_str!("../rules/rock-paper-scissors.txt")).expect("Rules are invalid"));
+pub static ROCK_PAPER_SCISSORS_LIZARD_SPOCK: LazyLock<Rules> =
+    LazyLock::new(|| Rules::from_table(include_str!("../rules/rock-paper-scissors-lizard-spock.txt")).expect("Rules are invalid"));
+
+#[test]
+fn test_classic_rules() {
+    let rules = &*ROCK_PAPER_SCISSORS;
+    let [rock, paper, scissors] = ["Rock", "Paper", "Scissors"].map(|name| rules.find(name).unwrap());
+
+    assert_eq!(rules.outcome(paper, rock), Outcome::Win);
+    assert_eq!(rules.outcome(rock, paper), Outcome::Loss);
+    assert_eq!(rules.outcome(scissors, scissors), Outcome::Draw);
+    assert_eq!(rules.score((rock, paper)), 8);
+    assert_eq!(rules.shape_for(rock, Outcome::Loss), Some(scissors));
+    assert_eq!(*rules, Rules::cyclic(&["Rock", "Paper", "Scissors"]).unwrap());
+}
+
+#[test]
+fn test_rock_paper_scissors_lizard_spock() {
+    let rules = &*ROCK_PAPER_SCISSORS_LIZARD_SPOCK;
+    let [spock, lizard] = ["Spock", "Lizard"].map(|name| rules.find(name).unwrap());
+
+    assert_eq!(rules.shapes().len(), 5);
+    assert_eq!(rules.outcome(lizard, spock), Outcome::Win);
+    assert_eq!(rules.score((spock, lizard)), 11);
+    assert_eq!(*rules, Rules::cyclic(&["Rock", "Paper", "Scissors", "Spock", "Lizard"]).unwrap());
+}
+
+#[test]
+fn test_cyclic_rules() {
+    let rules = Rules::cyclic(&["a", "b", "c", "d", "e", "f", "g"]).unwrap();
+
+    for shape in rules.shapes() {
+        let wins = rules.shapes().filter(|&other| rules.outcome(shape, other) == Outcome::Win).count();
+        assert_eq!(wins, 3);
+    }
+    assert!(Rules::cyclic(&["a", "b", "c", "d"]).is_err());
+    assert!(Rules::cyclic(&[]).is_err());
+}
+
+#[test]
+fn test_table_errors() {
+    let error = |table: &str| Rules::from_table(table).unwrap_err();
+
+    let unknown = error("win 6\ndraw 3\nloss 0\nRock 1 beats Scissors\nPaper 2 beats Rock\nScissors 3 beats Papr\n");
+    assert_eq!((unknown.line(), unknown.column()), (Some(6), Some(18)));
+
+    let both = error("win 6\ndraw 3\nloss 0\nRock 1 beats Paper\nPaper 2 beats Rock\n");
+    assert_eq!(both.line(), Some(5));
+
+    let twice = error("win 6\ndraw 3\nloss 0\nRock 1 beats Scissors Scissors\nPaper 2 beats Rock\nScissors 3 beats Paper\n");
+    assert_eq!((twice.line(), twice.column(), twice.message()), (Some(4), Some(23), "Scissors is listed twice"));
+
+    let undecided = error("win 6\ndraw 3\nloss 0\nRock 1\nPaper 2\n");
+    assert_eq!(undecided.message(), "Neither Rock nor Paper beats the other");
+
+    assert_eq!(error("win 6\nloss 0\nRock 1\n").message(), "No points for a draw");
+}
+
+// An index into the shapes of the `Rules` it came from, which are the only
+// rules it can be used with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Shape(usize);
+
+impl Shape {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Outcome::Loss => "loss",
+            Outcome::Draw => "draw",
+            Outcome::Win => "win",
+        };
+        s.fmt(f)
+    }
+}
+
+// A game where both players pick a shape each round, such as Rock Paper
+// Scissors. Every two shapes must have one beat the other, and a round
+// scores the points for its outcome plus the score of your shape.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    names: Vec<String>,
+    scores: Vec<i32>,
+    // beats[a][b] when shape a beats shape b
+    beats: Vec<Vec<bool>>,
+    // Indexed by Outcome
+    points: [i32; 3],
+}
+
+impl Rules {
+    // Tables have a line with the points for each outcome, such as "win 6",
+    // and a line for each shape, such as "Rock 1 beats Scissors Lizard".
+    // Blank lines and lines starting with # are skipped.
+    pub fn from_table(table: &str) -> Result<Rules, ParseError> {
+        let mut points = [None; 3];
+        let mut names: Vec<String> = Vec::new();
+        let mut scores = Vec::new();
+        let mut beaten = Vec::new();
+
+        for (i, line) in table.lines().enumerate() {
+            let line_number = i + 1;
+            let tokens = line.split_whitespace().collect::<Vec<&str>>();
+            let number = |token: &str| {
+                token
+                    .parse::<i32>()
+                    .map_err(|e| ParseError::at_token(line_number, line, token, format!("Not a score: {e}")))
+            };
+
+            match tokens[..] {
+                [] => {}
+                [first, ..] if first.starts_with('#') => {}
+                [outcome @ ("loss" | "draw" | "win"), value] => {
+                    let outcome = match outcome {
+                        "loss" => Outcome::Loss,
+                        "draw" => Outcome::Draw,
+                        _ => Outcome::Win,
+                    };
+                    if points[outcome as usize].is_some() {
+                        return Err(ParseError::at_token(line_number, line, tokens[0], format!("The points for a {outcome} are already given")));
+                    }
+                    points[outcome as usize] = Some(number(value)?);
+                }
+                [name, score, ref rest @ ..] => {
+                    let beats = match rest {
+                        [] => &[][..],
+                        ["beats", beats @ ..] => beats,
+                        [token, ..] => return Err(ParseError::at_token(line_number, line, token, "Expected \"beats\" and the shapes it beats")),
+                    };
+                    if names.iter().any(|n| n == name) {
+                        return Err(ParseError::at_token(line_number, line, name, format!("{name} is already a shape")));
+                    }
+
+                    names.push(name.to_string());
+                    scores.push(number(score)?);
+                    beaten.push((line_number, line, beats.to_vec()));
+                }
+                _ => return Err(ParseError::in_line(line_number, line, "Expected the points for an outcome or a shape with its score")),
+            }
+        }
+
+        // Shapes can beat shapes further down the table, so they are only
+        // looked up once every shape is known
+        let mut beats = vec![vec![false; names.len()]; names.len()];
+        for (a, (line_number, line, beaten)) in beaten.into_iter().enumerate() {
+            for token in beaten {
+                let b = names
+                    .iter()
+                    .position(|n| n == token)
+                    .ok_or_else(|| ParseError::at_token(line_number, line, token, format!("Unknown shape {token}")))?;
+
+                if a == b {
+                    return Err(ParseError::at_token(line_number, line, token, format!("{token} can't beat itself")));
+                }
+                if beats[a][b] {
+                    return Err(ParseError::at_token(line_number, line, token, format!("{token} is listed twice")));
+                }
+                if beats[b][a] {
+                    return Err(ParseError::at_token(line_number, line, token, format!("{token} already beats {}", names[a])));
+                }
+                beats[a][b] = true;
+            }
+        }
+
+        let points = [Outcome::Loss, Outcome::Draw, Outcome::Win]
+            .map(|outcome| points[outcome as usize].ok_or_else(|| ParseError::new(format!("No points for a {outcome}"))));
+        let [loss, draw, win] = points;
+
+        Rules::new(names, scores, beats, [loss?, draw?, win?])
+    }
+
+    // The shapes go round in a circle, each beating the shapes an odd number
+    // of places before it. With an odd number of shapes everyone beats half
+    // of the others, as in Rock Paper Scissors Lizard Spock in score order.
+    // Shapes score 1, 2, 3 and so on, and outcomes score as in the puzzle.
+    pub fn cyclic(names: &[&str]) -> Result<Rules, ParseError> {
+        let n = names.len();
+        if n.is_multiple_of(2) {
+            return Err(ParseError::new(format!("A cyclic game needs an odd number of shapes, not {n}")));
+        }
+
+        let beats = (0..n)
+            .map(|a| (0..n).map(|b| (a + n - b) % n % 2 == 1).collect())
+            .collect();
+
+        Rules::new(
+            names.iter().map(ToString::to_string).collect(),
+            (1..=n as i32).collect(),
+            beats,
+            [0, 3, 6],
+        )
+    }
+
+    fn new(names: Vec<String>, scores: Vec<i32>, beats: Vec<Vec<bool>>, points: [i32; 3]) -> Result<Rules, ParseError> {
+        if names.is_empty() {
+            return Err(ParseError::new("There are no shapes to play"));
+        }
+
+        for a in 0..names.len() {
+            for b in a + 1..names.len() {
+                if !beats[a][b] && !beats[b][a] {
+                    return Err(ParseError::new(format!("Neither {} nor {} beats the other", names[a], names[b])));
+                }
+            }
+        }
+
+        Ok(Rules {
+            names,
+            scores,
+            beats,
+            points,
+        })
+    }
+
+    // In table order
+    pub fn shapes(&self) -> impl ExactSizeIterator<Item = Shape> {
+        (0..self.names.len()).map(Shape)
+    }
+
+    pub fn find(&self, name: &str) -> Option<Shape> {
+        self.names.iter().position(|n| n == name).map(Shape)
+    }
+
+    pub fn name(&self, shape: Shape) -> &str {
+        &self.names[shape.0]
+    }
+
+    pub fn shape_score(&self, shape: Shape) -> i32 {
+        self.scores[shape.0]
+    }
+
+    pub fn outcome_score(&self, outcome: Outcome) -> i32 {
+        self.points[outcome as usize]
+    }
+
+    pub fn outcome(&self, your_shape: Shape, opponent_shape: Shape) -> Outcome {
+        if your_shape == opponent_shape {
+            Outcome::Draw
+        } else if self.beats[your_shape.0][opponent_shape.0] {
+            Outcome::Win
+        } else {
+            Outcome::Loss
+        }
+    }
+
+    // Your score for a round
+    pub fn score(&self, (opponent_shape, your_shape): (Shape, Shape)) -> i32 {
+        self.outcome_score(self.outcome(your_shape, opponent_shape)) + self.shape_score(your_shape)
+    }
+
+    // The first shape in the table to play for `outcome`, if there is one
+    pub fn shape_for(&self, opponent_shape: Shape, outcome: Outcome) -> Option<Shape> {
+        self.shapes().find(|&shape| self.outcome(shape, opponent_shape) == outcome)
+    }
+}
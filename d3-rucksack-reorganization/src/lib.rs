@@ -0,0 +1,138 @@
+pub mod rucksack;
+
+use std::io::BufRead;
+
+use aoc_common::{
+    generate::{Generate, Rng},
+    stream::{LineParser, Stream},
+    ParseError, Solution,
+};
+use tracing::trace;
+
+use crate::rucksack::{group_rucksacks_into_threes, parse_rucksack, Rucksack, RucksackGroup};
+
+pub struct RucksackReorganization;
+
+impl Solution for RucksackReorganization {
+    type Input = Vec<Rucksack>;
+    type AnswerA = i32;
+    type AnswerB = i32;
+
+    fn parse(input: &str) -> Result<Vec<Rucksack>, ParseError> {
+        let rucksacks = LineParser::new(input.as_bytes().lines(), parse_rucksack).collect::<Result<Vec<Rucksack>, ParseError>>()?;
+
+        if rucksacks.len() % 3 != 0 {
+            return Err(ParseError::new("The number of rucksacks are not divisible by 3."));
+        }
+
+        for (i, group) in group_rucksacks_into_threes(rucksacks.clone()).iter().enumerate() {
+            if group.find_common_item().is_none() {
+                return Err(ParseError::at_line(
+                    i * 3 + 1,
+                    "Group of three rucksacks has no item in common",
+                ));
+            }
+        }
+
+        Ok(rucksacks)
+    }
+
+    // Sum of priorities
+    fn part_a(rucksacks: &Vec<Rucksack>) -> i32 {
+        rucksacks
+            .iter()
+            .inspect(|rs| {
+                trace!(?rs, common = ?rs.compute_rucksack_common_items().iter().map(|&c| c as char).collect::<String>())
+            })
+            .map(Rucksack::compute_priority)
+            .inspect(|priority| trace!(priority))
+            .sum()
+    }
+
+    // Sum of Group Priorities
+    fn part_b(rucksacks: &Vec<Rucksack>) -> i32 {
+        group_rucksacks_into_threes(rucksacks.clone())
+            .iter()
+            .map(RucksackGroup::compute_priority)
+            .sum()
+    }
+}
+
+// Priorities for both parts, keeping one group of rucksacks at a time
+fn stream_priorities(reader: impl BufRead) -> Result<(i32, i32), ParseError> {
+    let (mut priorities, mut group_priorities) = (0, 0);
+    let mut group = Vec::with_capacity(3);
+
+    for (i, rucksack) in LineParser::new(reader.lines(), parse_rucksack).enumerate() {
+        let rucksack = rucksack?;
+        priorities += rucksack.compute_priority();
+        group.push(rucksack);
+
+        if group.len() == 3 {
+            let rucksack_group = RucksackGroup::new(&group);
+            group.clear();
+
+            if rucksack_group.find_common_item().is_none() {
+                return Err(ParseError::at_line(i - 1, "Group of three rucksacks has no item in common"));
+            }
+            group_priorities += rucksack_group.compute_priority();
+        }
+    }
+
+    if !group.is_empty() {
+        return Err(ParseError::new("The number of rucksacks are not divisible by 3."));
+    }
+
+    Ok((priorities, group_priorities))
+}
+
+impl Stream for RucksackReorganization {
+    fn stream_a(reader: impl BufRead) -> Result<i32, ParseError> {
+        Ok(stream_priorities(reader)?.0)
+    }
+
+    fn stream_b(reader: impl BufRead) -> Result<i32, ParseError> {
+        Ok(stream_priorities(reader)?.1)
+    }
+}
+
+// `size` groups of three, only their badge is in all three rucksacks of a
+// group and only one item is in both compartments of a rucksack
+impl Generate for RucksackReorganization {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut rucksacks = Vec::new();
+
+        for _ in 0..size {
+            let mut items = (b'a'..=b'z').chain(b'A'..=b'Z').collect::<Vec<u8>>();
+            rng.shuffle(&mut items);
+            let badge = items.pop().unwrap();
+
+            // Each rucksack of the group takes its other items from its own
+            // pool, and each compartment from its own half of that
+            for pool in items.chunks_exact(items.len() / 3) {
+                let (shared, rest) = pool.split_first().unwrap();
+                let (left, right) = rest.split_at(rest.len() / 2);
+                let len = rng.range(4..13) as usize;
+                let badge_left = rng.chance(0.5);
+
+                let mut compartment = |half: &[u8], with_badge: bool| {
+                    let mut compartment = vec![*shared];
+                    if with_badge {
+                        compartment.push(badge);
+                    }
+                    while compartment.len() < len {
+                        compartment.push(*rng.pick(half));
+                    }
+                    rng.shuffle(&mut compartment);
+                    compartment
+                };
+
+                let mut rucksack = compartment(left, badge_left);
+                rucksack.extend(compartment(right, !badge_left));
+                rucksacks.push(String::from_utf8(rucksack).unwrap());
+            }
+        }
+
+        rucksacks.join("\n")
+    }
+}
@@ -1,10 +1,11 @@
 use std::{
     collections::BTreeSet,
     fs::File,
-    io::{BufRead, BufReader, Error, Result},
-    usize,
+    io::{BufRead, BufReader},
 };
 
+use aoc_common::{stream::LineParser, ParseError};
+
 #[derive(Debug, Clone)]
 pub struct Rucksack {
     c1: String,
@@ -21,9 +22,13 @@ impl Rucksack {
             all: s,
         }
     }
+    pub fn items(&self) -> &str {
+        &self.all
+    }
+
     pub fn compute_rucksack_common_items(&self) -> BTreeSet<u8> {
-        let compartment1_set = BTreeSet::from_iter(self.c1.clone().into_bytes().into_iter());
-        let compartment2_set = BTreeSet::from_iter(self.c2.clone().into_bytes().into_iter());
+        let compartment1_set = BTreeSet::from_iter(self.c1.clone().into_bytes());
+        let compartment2_set = BTreeSet::from_iter(self.c2.clone().into_bytes());
 
         compartment1_set
             .intersection(&compartment2_set)
@@ -61,24 +66,23 @@ impl RucksackGroup {
         )
     }
 
-    pub fn find_common_item(&self) -> u8 {
-        let set0 = BTreeSet::from_iter(self.0.clone().all.into_bytes().into_iter());
-        let set1 = BTreeSet::from_iter(self.1.clone().all.into_bytes().into_iter());
-        let set2 = BTreeSet::from_iter(self.2.clone().all.into_bytes().into_iter());
+    pub fn find_common_item(&self) -> Option<u8> {
+        let set0 = BTreeSet::from_iter(self.0.clone().all.into_bytes());
+        let set1 = BTreeSet::from_iter(self.1.clone().all.into_bytes());
+        let set2 = BTreeSet::from_iter(self.2.clone().all.into_bytes());
 
         set0.intersection(&set1)
             .cloned()
             .collect::<BTreeSet<u8>>()
             .intersection(&set2)
-            .cloned()
-            .collect::<BTreeSet<u8>>()
-            .first()
-            .unwrap()
-            .clone()
+            .next()
+            .copied()
     }
 
+    // Groups without a common item have no priority
     pub fn compute_priority(&self) -> i32 {
-        Rucksack::compute_item_priority(self.find_common_item())
+        self.find_common_item()
+            .map_or(0, Rucksack::compute_item_priority)
     }
 }
 
@@ -90,75 +94,54 @@ struct RucksackParserItem {
 impl RucksackParserItem {
     fn parse_rucksack_string(&self) -> Rucksack {
         let s = self.rucksack_string.clone();
-        return Rucksack::new(s);
-    }
-
-    fn is_valid_rucksack_string(&self) -> bool {
-        self.rucksack_string.chars().all(|b| (b >= 'A' && b <= 'z'))
+        Rucksack::new(s)
     }
 
-    fn generate_parse_error(&self) -> Error {
-        let errstr = format!(
-            "Error Parsing line {}:\n
-{} contains values outside of [a-zA-Z]",
-            self.line_number, self.rucksack_string
-        );
+    // Points at the first item outside of [a-zA-Z], or the whole line when
+    // the items can't be split evenly between both compartments
+    fn find_parse_error(&self) -> Option<ParseError> {
+        let s = &self.rucksack_string;
 
-        Error::new(std::io::ErrorKind::InvalidInput, errstr)
-    }
-}
-
-pub trait Iter = Iterator<Item = Result<String>>;
+        if let Some((column, c)) = s.chars().enumerate().find(|(_, c)| !c.is_ascii_alphabetic()) {
+            return Some(
+                ParseError::in_line(self.line_number, s, format!("{c:?} is outside of [a-zA-Z]"))
+                    .with_span(column + 1, 1),
+            );
+        }
 
-pub struct RucksackParser<I: Iter> {
-    line_number: usize,
-    source: I,
-}
+        if !s.len().is_multiple_of(2) {
+            return Some(ParseError::in_line(
+                self.line_number,
+                s,
+                format!("{} items can't be split between two compartments", s.len()),
+            ));
+        }
 
-impl<I: Iter> RucksackParser<I> {
-    pub fn new(source: I) -> RucksackParser<I> {
-        return RucksackParser {
-            line_number: 0,
-            source,
-        };
+        None
     }
 }
 
-impl<I: Iter> Iterator for RucksackParser<I> {
-    type Item = Result<Rucksack>;
-
-    fn next(&mut self) -> Option<Result<Rucksack>> {
-        let x = match self.source.next()? {
-            Err(err) => Err(err),
-            Ok(rucksack_string) => {
-                self.line_number += 1;
-
-                let parse_item = RucksackParserItem {
-                    line_number: self.line_number,
-                    rucksack_string,
-                };
-
-                if parse_item.is_valid_rucksack_string() {
-                    Ok(parse_item.parse_rucksack_string())
-                } else {
-                    Err(parse_item.generate_parse_error())
-                }
-            }
-        };
-
-        Some(x)
+// A rucksack from a line of its items
+pub fn parse_rucksack(line_number: usize, line: &str) -> Result<Rucksack, ParseError> {
+    let parse_item = RucksackParserItem {
+        line_number,
+        rucksack_string: line.to_string(),
+    };
+
+    match parse_item.find_parse_error() {
+        None => Ok(parse_item.parse_rucksack_string()),
+        Some(err) => Err(err),
     }
 }
 
-pub fn read_file_rucksacks(filename: &str) -> Result<Vec<Rucksack>> {
-    let rucksacks = RucksackParser::new(BufReader::new(File::open(filename)?).lines())
-        .collect::<Result<Vec<Rucksack>>>()?;
+pub fn read_file_rucksacks(filename: &str) -> Result<Vec<Rucksack>, ParseError> {
+    let file = File::open(filename).map_err(|e| ParseError::new(e.to_string()).with_file(filename))?;
+    let rucksacks = LineParser::new(BufReader::new(file).lines(), parse_rucksack)
+        .collect::<Result<Vec<Rucksack>, ParseError>>()
+        .map_err(|e| e.with_file(filename))?;
 
     if rucksacks.len() % 3 != 0 {
-        Err(Error::new(
-            std::io::ErrorKind::InvalidInput,
-            "The number of rucksacks are not divisible by 3.",
-        ))
+        Err(ParseError::new("The number of rucksacks are not divisible by 3.").with_file(filename))
     } else {
         Ok(rucksacks)
     }
@@ -166,6 +149,6 @@ pub fn read_file_rucksacks(filename: &str) -> Result<Vec<Rucksack>> {
 
 pub fn group_rucksacks_into_threes(rucksacks: Vec<Rucksack>) -> Vec<RucksackGroup> {
     // assert it's divisibility by 3
-    assert!(rucksacks.len() % 3 == 0);
+    assert!(rucksacks.len().is_multiple_of(3));
     rucksacks.chunks_exact(3).map(RucksackGroup::new).collect()
 }
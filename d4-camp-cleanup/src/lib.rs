@@ -0,0 +1,178 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    sync::LazyLock,
+};
+
+use aoc_common::{
+    stream::{LineParser, Stream},
+    ParseError, Solution,
+};
+use itertools::Itertools;
+use regex::Regex;
+use tracing::trace;
+
+pub type ElfRange = (u8, u8, u8, u8);
+
+// Compiled once, parsers call these for every line
+static RANGE_SEPARATOR: LazyLock<Regex> = LazyLock::new(|| Regex::new("[,-]").expect("Regex is Invalid"));
+static LINE_MATCHER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\d{1,2}-\d{1,2},\d{1,2}-\d{1,2}$").expect("Regex is Invalid"));
+
+// s must be in the form xx-xx,xx-xx
+pub fn parse_elf_range(s: &str) -> ElfRange {
+    let elf_range: ElfRange = RANGE_SEPARATOR
+        .splitn(s, 4)
+        .map(|x| x.parse::<u8>().unwrap())
+        .next_tuple()
+        .unwrap();
+
+    elf_range
+}
+
+/*
+ * Checks whether the two ranges fully contain eachother, inclusive
+ *
+ * # Examples:
+ *
+ * |---|
+ * |---|
+ *
+ * |---|
+ *  |-|
+ *
+ *  |-|
+ * |---|
+ *
+ * # Invariants
+ * a1 <= a2
+ * b1 <= b2
+ */
+pub fn is_contained((a1, a2, b1, b2): &ElfRange) -> bool {
+    (a1 <= b1 && a2 >= b2) || (b1 <= a1 && b2 >= a2)
+}
+
+// easier to check if not overlapping?
+pub fn overlap((a1, a2, b1, b2): &ElfRange) -> bool {
+    !((a1 < b1 && a2 < b1) || (b1 < a1 && b2 < a1))
+}
+
+pub fn read_elf_range_lines(filename: &str) -> Result<Vec<String>, ParseError> {
+    let file = File::open(filename).map_err(|e| ParseError::new(e.to_string()).with_file(filename))?;
+    read_elf_ranges(BufReader::new(file)).map_err(|e| e.with_file(filename))
+}
+
+pub fn read_elf_ranges(reader: impl BufRead) -> Result<Vec<String>, ParseError> {
+    let lines = reader.lines();
+
+    let mut range_lines: Vec<String> = Vec::new();
+    let mut i = 0;
+
+    for line in lines {
+        i += 1;
+        let s = line.map_err(|e| ParseError::at_line(i, e.to_string()))?;
+
+        check_elf_range_line(i, &s)?;
+        range_lines.push(s);
+    }
+
+    Ok(range_lines)
+}
+
+fn check_elf_range_line(i: usize, s: &str) -> Result<(), ParseError> {
+    if !LINE_MATCHER.is_match(s) {
+        return Err(ParseError::in_line(i, s, "Expected two section ranges like 2-4,6-8"));
+    }
+
+    // Ranges are used by is_contained and overlap assuming they don't run backwards
+    for range in s.split(',') {
+        let (start, end) = range.split_once('-').expect("Checked by the line matcher");
+        if start.parse::<u8>().ok() > end.parse::<u8>().ok() {
+            return Err(ParseError::at_token(i, s, range, "Section range ends before it starts"));
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_elf_range_line(i: usize, s: &str) -> Result<ElfRange, ParseError> {
+    check_elf_range_line(i, s)?;
+    Ok(parse_elf_range(s))
+}
+
+pub struct CampCleanup;
+
+impl Solution for CampCleanup {
+    type Input = Vec<ElfRange>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Vec<ElfRange>, ParseError> {
+        let lines = read_elf_ranges(input.as_bytes())?;
+        Ok(lines.iter().map(|s| parse_elf_range(s)).collect())
+    }
+
+    // Number of contained Elves
+    fn part_a(elf_ranges: &Vec<ElfRange>) -> usize {
+        elf_ranges.iter().filter(|r| is_contained(r)).count()
+    }
+
+    // Number of overlapping Elves
+    fn part_b(elf_ranges: &Vec<ElfRange>) -> usize {
+        elf_ranges
+            .iter()
+            .filter(|r| overlap(r))
+            .inspect(|range| trace!(?range, "overlapping"))
+            .count()
+    }
+}
+
+impl Stream for CampCleanup {
+    fn stream_a(reader: impl BufRead) -> Result<usize, ParseError> {
+        let mut contained = 0;
+        for range in LineParser::new(reader.lines(), parse_elf_range_line) {
+            contained += usize::from(is_contained(&range?));
+        }
+        Ok(contained)
+    }
+
+    fn stream_b(reader: impl BufRead) -> Result<usize, ParseError> {
+        let mut overlapping = 0;
+        for range in LineParser::new(reader.lines(), parse_elf_range_line) {
+            overlapping += usize::from(overlap(&range?));
+        }
+        Ok(overlapping)
+    }
+}
+
+#[cfg(test)]
+mod properties {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // Two ranges that are not backwards
+    fn elf_range() -> impl Strategy<Value = ElfRange> {
+        (0..100u8, 0..100u8, 0..100u8, 0..100u8)
+            .prop_map(|(a1, a2, b1, b2)| (a1.min(a2), a1.max(a2), b1.min(b2), b1.max(b2)))
+    }
+
+    proptest! {
+        #[test]
+        fn overlap_is_symmetric(r in elf_range()) {
+            let (a1, a2, b1, b2) = r;
+            prop_assert_eq!(overlap(&r), overlap(&(b1, b2, a1, a2)));
+        }
+
+        #[test]
+        fn containment_implies_overlap(r in elf_range()) {
+            prop_assert!(!is_contained(&r) || overlap(&r));
+        }
+
+        #[test]
+        fn overlap_means_a_shared_section(r in elf_range()) {
+            let (a1, a2, b1, b2) = r;
+            prop_assert_eq!(overlap(&r), (a1..=a2).any(|s| (b1..=b2).contains(&s)));
+        }
+    }
+}
@@ -0,0 +1,452 @@
+use std::fmt::Display;
+
+use aoc_common::{
+    generate::{Generate, Rng},
+    ParseError, Solution,
+};
+use itertools::Itertools;
+use regex::Regex;
+
+#[derive(Debug)]
+struct RawRearrangementProcedure {
+    number_of_stacks: usize,
+    crate_stack_lines: Vec<String>,
+    // Moves are kept with their line number for error reporting
+    move_lines: Vec<(usize, String)>,
+}
+
+#[derive(Debug)]
+enum ParseState {
+    Crates,
+    Numbers,
+    Space,
+    Moves,
+    Unknown,
+}
+
+fn read_procedure(data: &str) -> Result<RawRearrangementProcedure, ParseError> {
+    use ParseState::{Crates, Moves, Numbers, Space, Unknown};
+
+    let lines: Vec<&str> = data.lines().collect();
+
+    let line_matcher = Regex::new(r"^(    |\[\w\] )*(   |\[\w\])$").unwrap();
+    let stack_number_regex = Regex::new(r"^ (\d   )*\d$").unwrap();
+    let move_regex = Regex::new(r"^move \d+ from \d+ to \d+$").unwrap();
+
+    let mut crate_stacks: Vec<String> = Vec::new();
+    let mut number_of_stacks: usize = 0;
+    let mut moves: Vec<(usize, String)> = Vec::new();
+
+    let mut i = 0;
+
+    let mut parse_state: ParseState;
+    for line in lines {
+        let s = line.to_string();
+        i += 1;
+
+        if line_matcher.is_match(&s) {
+            parse_state = Crates;
+        } else if stack_number_regex.is_match(&s) {
+            parse_state = Numbers;
+        } else if s.is_empty() {
+            parse_state = Space;
+        } else if move_regex.is_match(&s) {
+            parse_state = Moves;
+        } else {
+            parse_state = Unknown;
+        }
+
+        match parse_state {
+            Crates => crate_stacks.push(s.clone()),
+            Numbers => {
+                number_of_stacks = s
+                    .split(' ')
+                    .next_back()
+                    .and_then(|n| n.parse::<usize>().ok())
+                    .ok_or_else(|| ParseError::at_line(i, "Invalid stack numbers"))?
+            }
+            Space => continue,
+            Moves => moves.push((i, s)),
+            Unknown => return Err(ParseError::in_line(i, &s, "Not a crate, stack number or move line")),
+        }
+    }
+
+    crate_stacks.reverse();
+
+    Ok(RawRearrangementProcedure {
+        crate_stack_lines: crate_stacks,
+        number_of_stacks,
+        move_lines: moves,
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct Move {
+    count: i32,
+    from: usize,
+    to: usize,
+}
+
+impl Move {
+    pub fn count(&self) -> i32 {
+        self.count
+    }
+
+    pub fn from(&self) -> usize {
+        self.from
+    }
+
+    pub fn to(&self) -> usize {
+        self.to
+    }
+
+    // Stacks are numbered from 1 in the procedure, but indexed from 0
+    fn parse(line_number: usize, value: &str, number_of_stacks: usize) -> Result<Move, ParseError> {
+        let err = |token: &str, s: &str| ParseError::at_token(line_number, value, token, s);
+        let stack = |n: &str| match n.parse::<usize>() {
+            Ok(n) if (1..=number_of_stacks).contains(&n) => Ok(n - 1),
+            _ => Err(err(n, "Stack number does not exist")),
+        };
+
+        let (_, count, _, from, _, to) = value
+            .splitn(6, ' ')
+            .next_tuple()
+            .ok_or_else(|| ParseError::in_line(line_number, value, "Expected move n from a to b"))?;
+
+        Ok(Move {
+            count: count.parse().map_err(|_| err(count, "Invalid crate count"))?,
+            from: stack(from)?,
+            to: stack(to)?,
+        })
+    }
+}
+
+type StackCommand = (usize, char);
+
+fn parse_crates(s: &String) -> Vec<StackCommand> {
+    let mut v = Vec::new();
+    let mut i = 0;
+    let mut index = i + 1;
+
+    let chars = s.as_bytes();
+
+    while index < s.len() {
+        let c = chars[index];
+
+        if c.is_ascii_alphabetic() {
+            v.push((i, c as char));
+        }
+
+        i += 1;
+        index = 4 * i + 1;
+    }
+
+    v
+}
+
+struct EmptyStacks {
+    stack_count: usize,
+    stacks: Vec<Vec<char>>,
+}
+
+impl EmptyStacks {
+    fn build_empty_stack(stack_count: usize) -> EmptyStacks {
+        let mut s = EmptyStacks {
+            stack_count,
+            stacks: Vec::new(),
+        };
+
+        for _ in 0..stack_count {
+            s.stacks.push(Vec::new());
+        }
+
+        s
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Stacks {
+    stack_count: usize,
+    stacks: Vec<Vec<char>>,
+}
+
+impl Stacks {
+    // Each stack from the bottom crate up
+    pub fn crates(&self) -> &[Vec<char>] {
+        &self.stacks
+    }
+
+    fn build_stack(
+        EmptyStacks {
+            stack_count,
+            mut stacks,
+        }: EmptyStacks,
+        crate_lines: Vec<Vec<StackCommand>>,
+    ) -> Result<Stacks, ParseError> {
+        for crate_line in crate_lines {
+            for (i, c) in crate_line {
+                if i >= stack_count {
+                    return Err(ParseError::new(format!("Crate {c} is outside of the {stack_count} stacks")));
+                }
+                stacks[i].push(c);
+            }
+        }
+
+        Ok(Stacks {
+            stack_count,
+            stacks,
+        })
+    }
+
+    // The crate movers pop crates without checking, so make sure no move
+    // takes more crates than its stack holds at that point.
+    fn check_moves(&self, moves: &[(usize, Move)]) -> Result<(), ParseError> {
+        let mut heights = self.stacks.iter().map(Vec::len).collect::<Vec<usize>>();
+
+        for (line_number, Move { count, from, to }) in moves {
+            let count = *count as usize;
+            if heights[*from] < count {
+                return Err(ParseError::at_line(
+                    *line_number,
+                    format!("Cannot move {count} crates from stack {}, it only has {}", from + 1, heights[*from]),
+                ));
+            }
+
+            heights[*from] -= count;
+            heights[*to] += count;
+        }
+
+        Ok(())
+    }
+}
+
+pub trait CrateMover {
+    fn move_crates(s: &mut Stacks, mv: Move);
+}
+
+pub struct CrateMover9000;
+
+impl CrateMover for CrateMover9000 {
+    fn move_crates(s: &mut Stacks, Move { count, from, to }: Move) {
+        for _ in 0..count {
+            let x = s.stacks[from].pop().unwrap();
+            s.stacks[to].push(x);
+        }
+    }
+}
+
+pub struct CrateMover9001;
+
+impl CrateMover for CrateMover9001 {
+    fn move_crates(s: &mut Stacks, Move { count, from, to }: Move) {
+
+        let mut crate_list = Vec::new();
+        for _ in 0..count {
+            let x = s.stacks[from].pop().unwrap();
+            crate_list.push(x);
+        }
+
+        crate_list.reverse();
+        s.stacks[to].append(&mut crate_list);
+    }
+}
+
+impl Display for Stacks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut s = "".to_string();
+
+        if self.stack_count != 0 {
+            for i in 0..self.stack_count {
+                if let Some(c) = self.stacks[i].last() {
+                    s.push(*c);
+                }
+            }
+        }
+
+        s.fmt(f)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Procedure {
+    stacks: Stacks,
+    moves: Vec<Move>,
+}
+
+impl Procedure {
+    pub fn stacks(&self) -> &Stacks {
+        &self.stacks
+    }
+
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+}
+
+fn parse_procedure(input: &str) -> Result<Procedure, ParseError> {
+    let RawRearrangementProcedure {
+        number_of_stacks,
+        crate_stack_lines,
+        move_lines,
+    } = read_procedure(input)?;
+
+    let es = EmptyStacks::build_empty_stack(number_of_stacks);
+
+    let crates = crate_stack_lines
+        .iter()
+        .map(parse_crates)
+        .collect::<Vec<Vec<StackCommand>>>();
+
+    let stacks = Stacks::build_stack(es, crates)?;
+
+    let moves = move_lines
+        .iter()
+        .map(|(i, line)| Ok((*i, Move::parse(*i, line, number_of_stacks)?)))
+        .collect::<Result<Vec<(usize, Move)>, ParseError>>()?;
+
+    stacks.check_moves(&moves)?;
+
+    Ok(Procedure {
+        stacks,
+        moves: moves.into_iter().map(|(_, mv)| mv).collect(),
+    })
+}
+
+pub fn rearrange<M: CrateMover>(procedure: &Procedure) -> Stacks {
+    let mut s = procedure.stacks.clone();
+
+    procedure
+        .moves
+        .iter()
+        .cloned()
+        .for_each(|mv| M::move_crates(&mut s, mv));
+
+    s
+}
+
+pub struct SupplyStacks;
+
+impl Solution for SupplyStacks {
+    type Input = Procedure;
+    type AnswerA = Stacks;
+    type AnswerB = Stacks;
+
+    fn parse(input: &str) -> Result<Procedure, ParseError> {
+        parse_procedure(input)
+    }
+
+    fn part_a(procedure: &Procedure) -> Stacks {
+        rearrange::<CrateMover9000>(procedure)
+    }
+
+    fn part_b(procedure: &Procedure) -> Stacks {
+        rearrange::<CrateMover9001>(procedure)
+    }
+}
+
+// `size` moves between up to nine stacks. Moves always leave a crate behind,
+// so no stack ever runs out and every stack has a top crate at the end.
+impl Generate for SupplyStacks {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let stack_count = rng.range(3..10) as usize;
+        let mut heights = (0..stack_count).map(|_| rng.range(1..8) as usize).collect::<Vec<usize>>();
+        heights[0] = heights[0].max(2);
+
+        let tallest = heights.iter().copied().max().unwrap();
+        let mut lines = (0..tallest)
+            .rev()
+            .map(|level| {
+                heights
+                    .iter()
+                    .map(|&h| match h > level {
+                        true => format!("[{}]", (b'A' + rng.below(26) as u8) as char),
+                        false => "   ".to_string(),
+                    })
+                    .join(" ")
+            })
+            .collect::<Vec<String>>();
+
+        lines.push(format!(" {}", (1..=stack_count).join("   ")));
+        lines.push(String::new());
+
+        for _ in 0..size {
+            let from = *rng.pick(&(0..stack_count).filter(|&i| heights[i] >= 2).collect::<Vec<usize>>());
+            let to = *rng.pick(&(0..stack_count).filter(|&i| i != from).collect::<Vec<usize>>());
+            let count = rng.range(1..heights[from] as i64) as usize;
+
+            heights[from] -= count;
+            heights[to] += count;
+            lines.push(format!("move {count} from {} to {}", from + 1, to + 1));
+        }
+
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod differential {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // What the crate movers should do, all of the top `count` crates at once.
+    // The CrateMover 9000 takes them one at a time, so they land reversed.
+    fn reference(procedure: &Procedure, reversed: bool) -> Vec<Vec<char>> {
+        let mut stacks = procedure.stacks.stacks.clone();
+
+        for &Move { count, from, to } in &procedure.moves {
+            let at = stacks[from].len() - count as usize;
+            let mut moved = stacks[from].split_off(at);
+            if reversed {
+                moved.reverse();
+            }
+            stacks[to].append(&mut moved);
+        }
+
+        stacks
+    }
+
+    // Moves never take more crates than their stack holds and never go back
+    // onto the same stack, like the puzzle's
+    fn procedure() -> impl Strategy<Value = Procedure> {
+        let stacks = prop::collection::vec(prop::collection::vec(prop::char::range('A', 'Z'), 0..6), 1..10);
+        let moves = prop::collection::vec((0..9usize, 0..9usize, 1..6usize), 0..20);
+
+        (stacks, moves).prop_map(|(stacks, moves)| {
+            let stack_count = stacks.len();
+            let mut heights = stacks.iter().map(Vec::len).collect::<Vec<usize>>();
+
+            let moves = moves
+                .into_iter()
+                .filter_map(|(from, to, count)| {
+                    let (from, to) = (from % stack_count, to % stack_count);
+                    let count = count.min(heights[from]);
+                    if from == to || count == 0 {
+                        return None;
+                    }
+
+                    heights[from] -= count;
+                    heights[to] += count;
+                    Some(Move { count: count as i32, from, to })
+                })
+                .collect();
+
+            Procedure {
+                stacks: Stacks { stack_count, stacks },
+                moves,
+            }
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn crate_mover_9000_matches_reference(procedure in procedure()) {
+            prop_assert_eq!(rearrange::<CrateMover9000>(&procedure).stacks, reference(&procedure, true));
+        }
+
+        #[test]
+        fn crate_mover_9001_matches_reference(procedure in procedure()) {
+            prop_assert_eq!(rearrange::<CrateMover9001>(&procedure).stacks, reference(&procedure, false));
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use std::{collections::{HashSet, LinkedList}, hash::Hash};
+
+use aoc_common::{ParseError, Solution};
+
+fn is_all_unique<T>(i: T) -> bool
+where
+    T: IntoIterator,
+    T::Item: Eq + Hash + Clone,
+{
+    let mut uniq = HashSet::new();
+    i.into_iter().all(move |x| uniq.insert(x))
+}
+
+pub fn find_marker(s: &str, size: usize) -> Option<usize> {
+    let mut b = s.bytes();
+
+    let mut i = size;
+
+
+    let mut cs: LinkedList<u8> = LinkedList::new();
+
+    for _ in 0..size {
+        cs.push_back(b.next()?);
+    }
+
+    while !is_all_unique(cs.clone()) {
+
+        let c = b.next()?;
+        i += 1;
+        cs.push_back(c);
+        cs.pop_front();
+
+    }
+
+    Some(i)
+}
+
+pub struct TuningTrouble;
+
+impl Solution for TuningTrouble {
+    type Input = String;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        let datastream = input.trim_end();
+
+        if let Some((i, c)) = datastream.chars().enumerate().find(|(_, c)| !c.is_ascii_lowercase()) {
+            return Err(ParseError::at_line(1, format!("{c:?} is not a lowercase letter")).with_span(i + 1, 1));
+        }
+
+        // A start-of-message marker also contains a start-of-packet marker
+        if find_marker(datastream, 14).is_none() {
+            return Err(ParseError::new("No start-of-message marker"));
+        }
+
+        Ok(datastream.to_string())
+    }
+
+    // Start-of-packet marker
+    fn part_a(datastream: &String) -> usize {
+        find_marker(datastream, 4).expect("Checked while parsing")
+    }
+
+    // Start-of-message marker
+    fn part_b(datastream: &String) -> usize {
+        find_marker(datastream, 14).expect("Checked while parsing")
+    }
+}
+
+#[cfg(test)]
+mod properties {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // Position after the first `size` characters that are all different
+    fn find_marker_slowly(s: &str, size: usize) -> Option<usize> {
+        let bytes = s.as_bytes();
+        (size..=bytes.len()).find(|&end| {
+            let window = &bytes[end - size..end];
+            window.iter().all(|c| window.iter().filter(|&d| d == c).count() == 1)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn find_marker_agrees_with_brute_force(s in "[a-f]{0,40}", size in 1..8usize) {
+            prop_assert_eq!(find_marker(&s, size), find_marker_slowly(&s, size));
+        }
+    }
+}
@@ -41,6 +41,12 @@ pub struct DirectoryManager {
     directories: Vec<Directory>,
 }
 
+impl Default for DirectoryManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl DirectoryManager {
     pub fn new() -> Self {
         let mut directories = Vec::new();
@@ -55,9 +61,12 @@ impl DirectoryManager {
         }
     }
 
-    pub fn cd(&mut self, dir: &str) {
+    pub fn cd(&mut self, dir: &str) -> Result<(), String> {
         match dir {
             ".." => {
+                if self.current_directory.len() == 1 {
+                    return Err("Cannot move out of the root directory".to_string());
+                }
                 self.current_directory.pop();
             }
             "/" => {
@@ -65,17 +74,21 @@ impl DirectoryManager {
                 self.current_directory.push(0);
             }
             dir => {
-                let i = self.get_dir_index(dir);
+                let i = self
+                    .get_dir_index(dir)
+                    .ok_or(format!("No directory named {dir}"))?;
                 self.current_directory.push(i);
             }
         }
+
+        Ok(())
     }
 
     pub fn create_dir(&mut self, dir: &str) {
         let index = self.directories.len();
         let cwd = self.get_current_directory_mut();
 
-        if let None = cwd.get_directory_index(&dir.to_string()) {
+        if cwd.get_directory_index(&dir.to_string()).is_none() {
             cwd.add_subdirectory(dir.to_string(), index);
             self.directories
                 .push(Directory::new(dir.to_string(), index));
@@ -89,10 +102,29 @@ impl DirectoryManager {
 
     pub fn compute_dir_sizes(&self) -> Vec<u64> {
         self.directories.iter()
-                        .map(|dir| self.recursive_dir_size(&dir))
+                        .map(|dir| self.recursive_dir_size(dir))
                         .collect()
     }
 
+    // Full path of every directory, in the same order as compute_dir_sizes
+    pub fn compute_dir_paths(&self) -> Vec<String> {
+        let mut paths = vec![String::new(); self.directories.len()];
+        paths[0] = "/".to_string();
+        let mut unvisited = vec![0];
+
+        while let Some(i) = unvisited.pop() {
+            for (name, &child) in &self.directories[i].subdirectories {
+                paths[child] = match i {
+                    0 => format!("/{name}"),
+                    _ => format!("{}/{name}", paths[i]),
+                };
+                unvisited.push(child);
+            }
+        }
+
+        paths
+    }
+
     fn recursive_dir_size(&self, dir: &Directory) -> u64 {
 
         fn f(d: &Directory, ds: &Vec<Directory>) -> u64 {
@@ -102,9 +134,7 @@ impl DirectoryManager {
                 return file_sizes
             }
 
-            return file_sizes + d.subdirectories
-                                 .iter()
-                                 .map(|(_, i)| &ds[*i])
+            file_sizes + d.subdirectories.values().map(|i| &ds[*i])
                                  .map(|d| f(d, ds))
                                  .sum::<u64>()
         }
@@ -122,8 +152,8 @@ impl DirectoryManager {
         &mut self.directories[i]
     }
 
-    fn get_dir_index(&self, dir: &str) -> usize {
+    fn get_dir_index(&self, dir: &str) -> Option<usize> {
         let cwd = self.get_current_directory();
-        *cwd.get_directory_index(&dir.to_string()).unwrap()
+        cwd.get_directory_index(&dir.to_string()).copied()
     }
 }
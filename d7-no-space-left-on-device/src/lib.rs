@@ -0,0 +1,128 @@
+use std::{cmp::Reverse, collections::HashSet};
+
+use aoc_common::{
+    generate::{Generate, Rng},
+    ParseError, Solution,
+};
+use tracing::trace;
+
+pub mod directory_manager;
+use directory_manager::DirectoryManager;
+
+fn is_numeric(s: &str) -> bool {
+    s.chars().all(|x| x.is_ascii_digit())
+}
+
+pub fn parse_directory(s: &str) -> Result<DirectoryManager, ParseError> {
+    let lines = s.lines().enumerate();
+
+    let mut manager = DirectoryManager::new();
+
+    for (i, line) in lines {
+        let err = |s: &str| ParseError::in_line(i + 1, line, s);
+        let tokens = line.split(' ').collect::<Vec<&str>>();
+        trace!(?manager, ?tokens);
+        match &tokens[..] {
+            ["$", "cd", dir] => manager.cd(dir).map_err(|e| err(&e))?,
+            ["$", "ls"] => {}
+            ["dir", dirname] => manager.create_dir(dirname),
+            [size, filename] if is_numeric(size) => {
+                let fsize = size
+                    .parse::<u64>()
+                    .map_err(|_| ParseError::at_token(i + 1, line, size, "File is too large"))?;
+                manager.create_file(filename, fsize);
+            }
+            _ => return Err(err("Invalid Input")),
+        };
+    }
+
+    Ok(manager)
+}
+
+pub struct NoSpaceLeftOnDevice;
+
+impl Solution for NoSpaceLeftOnDevice {
+    type Input = DirectoryManager;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Result<DirectoryManager, ParseError> {
+        parse_directory(input)
+    }
+
+    // Total size of the directories of at most 100000
+    fn part_a(manager: &DirectoryManager) -> u64 {
+        let sizes = manager.compute_dir_sizes();
+
+        sizes.iter().filter(|x| **x <= 100000).sum()
+    }
+
+    // Size of the smallest directory that frees up enough space
+    fn part_b(manager: &DirectoryManager) -> u64 {
+        let sizes = manager.compute_dir_sizes();
+
+        let total_size = sizes[0];
+        let mut deletion_candidates = sizes
+            .iter()
+            .map(|x| (*x, total_size - *x))
+            .filter(|(_, x)| *x <= (70000000 - 30000000))
+            .collect::<Vec<(u64, u64)>>();
+
+        deletion_candidates.sort_by_key(|c| Reverse(c.1));
+
+        // Deleting the root directory always frees up enough space
+        deletion_candidates.first().map_or(total_size, |c| c.0)
+    }
+}
+
+const EXTENSIONS: [&str; 4] = [".txt", ".dat", ".log", ".lst"];
+
+// Unique in the whole tree, so that no directory lists the same name twice
+fn random_name(rng: &mut Rng, used: &mut HashSet<String>, extension: bool) -> String {
+    loop {
+        let mut name = (0..rng.range(1..8)).map(|_| (b'a' + rng.below(26) as u8) as char).collect::<String>();
+        if extension && rng.chance(0.5) {
+            let extension = rng.pick(&EXTENSIONS);
+            name.push_str(extension);
+        }
+        if used.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+// Lists `dir` and then goes into each of its subdirectories and back out
+fn write_transcript(dir: usize, children: &[Vec<usize>], names: &[String], rng: &mut Rng, used: &mut HashSet<String>, lines: &mut Vec<String>) {
+    lines.push("$ ls".to_string());
+    for &child in &children[dir] {
+        lines.push(format!("dir {}", names[child]));
+    }
+    for _ in 0..rng.range(0..5) {
+        lines.push(format!("{} {}", rng.range(1000..300000), random_name(rng, used, true)));
+    }
+
+    for &child in &children[dir] {
+        lines.push(format!("$ cd {}", names[child]));
+        write_transcript(child, children, names, rng, used, lines);
+        lines.push("$ cd ..".to_string());
+    }
+}
+
+// A shell session exploring a random tree of `size` directories
+impl Generate for NoSpaceLeftOnDevice {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut used = HashSet::new();
+        let mut names = vec!["/".to_string()];
+        let mut children = vec![Vec::new()];
+
+        for dir in 1..size.max(1) {
+            names.push(random_name(rng, &mut used, false));
+            children.push(Vec::new());
+            children[rng.below(dir)].push(dir);
+        }
+
+        let mut lines = vec!["$ cd /".to_string()];
+        write_transcript(0, &children, &names, rng, &mut used, &mut lines);
+        lines.join("\n")
+    }
+}
@@ -0,0 +1,103 @@
+use aoc_common::{
+    render::{Cell, Frame, Render, Visualize},
+    Grid, ParseError, Part, Solution,
+};
+
+pub mod scenic;
+pub mod visibility;
+
+pub fn parse_tree_grid(input: &str) -> Result<Grid<i32>, ParseError> {
+    Grid::parse_chars(input, |_, c| {
+        c.to_digit(10)
+            .map(|height| height as i32)
+            .ok_or_else(|| ParseError::new(format!("Invalid tree height {c:?}")))
+    })
+}
+
+// Tree heights, with the trees of interest picked out
+pub struct Forest<'a> {
+    pub heights: &'a Grid<i32>,
+    pub marked: Grid<bool>,
+}
+
+impl Render for Forest<'_> {
+    fn render(&self) -> Frame {
+        Frame::from_fn(self.heights.rows(), self.heights.cols(), |p| {
+            let height = self.heights[p];
+            let ch = char::from_digit(height as u32, 10).unwrap_or('?');
+            let light = (height * 16) as u8;
+
+            if self.marked[p] {
+                Cell::new(ch, [90 + light, 200 + light / 3, 60])
+            } else {
+                Cell::new(ch, [40 + light / 3, 60 + light / 3, 50])
+            }
+        })
+    }
+}
+
+fn visibility_map(tree_grid: &Grid<i32>) -> Grid<bool> {
+    use crate::visibility::*;
+
+    let lm = left_map(tree_grid);
+    let rm = right_map(tree_grid);
+    let tm = top_map(tree_grid);
+    let bm = bottom_map(tree_grid);
+
+    let mm = min_maps(&[&lm, &rm, &tm, &bm]);
+    apply_visibility_map(tree_grid, &mm)
+}
+
+fn scenic_map(tree_grid: &Grid<i32>) -> Grid<i32> {
+    use crate::scenic::*;
+
+    let lm = left_map(tree_grid);
+    let rm = right_map(tree_grid);
+    let tm = top_map(tree_grid);
+    let bm = bottom_map(tree_grid);
+
+    mult_maps(&[&lm, &rm, &tm, &bm])
+}
+
+pub struct TreetopTreeHouse;
+
+impl Solution for TreetopTreeHouse {
+    type Input = Grid<i32>;
+    type AnswerA = usize;
+    type AnswerB = i32;
+
+    fn parse(input: &str) -> Result<Grid<i32>, ParseError> {
+        parse_tree_grid(input)
+    }
+
+    // Visible Trees
+    fn part_a(tree_grid: &Grid<i32>) -> usize {
+        visibility_map(tree_grid).values().filter(|x| **x).count()
+    }
+
+    // Most Scenic Value
+    fn part_b(tree_grid: &Grid<i32>) -> i32 {
+        crate::scenic::find_most_scenic_value(&scenic_map(tree_grid))
+    }
+}
+
+impl Visualize for TreetopTreeHouse {
+    // A single frame, with the visible trees for part A and the most scenic
+    // ones for part B
+    fn frames(tree_grid: &Grid<i32>, part: Part, _every: usize) -> Vec<Frame> {
+        let marked = match part {
+            Part::A => visibility_map(tree_grid),
+            Part::B => {
+                let scenic = scenic_map(tree_grid);
+                let best = crate::scenic::find_most_scenic_value(&scenic);
+                scenic.map(|&score| score == best)
+            }
+        };
+
+        vec![Forest {
+            heights: tree_grid,
+            marked,
+        }
+        .render()]
+    }
+}
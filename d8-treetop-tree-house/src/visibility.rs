@@ -1,43 +1,17 @@
-use std::fmt::Debug;
+use aoc_common::Grid;
 
-pub type TreeGrid<T> = Vec<Vec<T>>;
-
-pub fn create_empty_grid<T>(size: usize, default: T) -> TreeGrid<T>
-where
-    T: Copy,
-{
-    let mut map = Vec::new();
-
-    for i in 0..size {
-        map.push(Vec::new());
-        for _ in 0..size {
-            map[i].push(default);
-        }
-    }
-
-    map
-}
-
-pub fn print_grid<T>(g: &TreeGrid<T>)
-where
-    T: Debug,
-{
-    g.iter().for_each(|x| println!("{x:?}"));
-}
-
-pub fn left_map(tree_grid: &TreeGrid<i32>) -> TreeGrid<i32> {
-    let size = tree_grid.len();
-    let mut map = create_empty_grid(size, 0);
+pub fn left_map(tree_grid: &Grid<i32>) -> Grid<i32> {
+    let mut map = Grid::new(tree_grid.rows(), tree_grid.cols(), 0);
 
     // Iterate through rows
-    for i in 0..size {
+    for i in 0..tree_grid.rows() {
         let mut prev = -1;
 
         // Iterate through Columns
         // From Left to Right
-        for j in (0..size).rev() {
-            let current = tree_grid[i][j];
-            map[i][j] = prev;
+        for j in (0..tree_grid.cols()).rev() {
+            let current = tree_grid[(i, j)];
+            map[(i, j)] = prev;
             prev = current.max(prev);
         }
     }
@@ -45,19 +19,18 @@ pub fn left_map(tree_grid: &TreeGrid<i32>) -> TreeGrid<i32> {
     map
 }
 
-pub fn right_map(tree_grid: &TreeGrid<i32>) -> TreeGrid<i32> {
-    let size = tree_grid.len();
-    let mut map = create_empty_grid(size, 0);
+pub fn right_map(tree_grid: &Grid<i32>) -> Grid<i32> {
+    let mut map = Grid::new(tree_grid.rows(), tree_grid.cols(), 0);
 
     // Iterate through rows
-    for i in 0..size {
+    for i in 0..tree_grid.rows() {
         let mut prev = -1;
 
         // Iterate through Columns
         // From Left to Right
-        for j in 0..size {
-            let current = tree_grid[i][j];
-            map[i][j] = prev;
+        for j in 0..tree_grid.cols() {
+            let current = tree_grid[(i, j)];
+            map[(i, j)] = prev;
             prev = current.max(prev);
         }
     }
@@ -65,19 +38,18 @@ pub fn right_map(tree_grid: &TreeGrid<i32>) -> TreeGrid<i32> {
     map
 }
 
-pub fn top_map(tree_grid: &TreeGrid<i32>) -> TreeGrid<i32> {
-    let size = tree_grid.len();
-    let mut map = create_empty_grid(size, -1);
+pub fn top_map(tree_grid: &Grid<i32>) -> Grid<i32> {
+    let mut map = Grid::new(tree_grid.rows(), tree_grid.cols(), -1);
 
-    // Iterate through rows
-    for i in 0..size {
+    // Iterate through columns
+    for i in 0..tree_grid.cols() {
         let mut prev = -1;
 
-        // Iterate through Columns
-        // From Left to Right
-        for j in (0..size).rev() {
-            let current = tree_grid[j][i];
-            map[j][i] = prev;
+        // Iterate through Rows
+        // From Bottom to Top
+        for j in (0..tree_grid.rows()).rev() {
+            let current = tree_grid[(j, i)];
+            map[(j, i)] = prev;
             prev = current.max(prev);
         }
     }
@@ -85,19 +57,18 @@ pub fn top_map(tree_grid: &TreeGrid<i32>) -> TreeGrid<i32> {
     map
 }
 
-pub fn bottom_map(tree_grid: &TreeGrid<i32>) -> TreeGrid<i32> {
-    let size = tree_grid.len();
-    let mut map = create_empty_grid(size, -1);
+pub fn bottom_map(tree_grid: &Grid<i32>) -> Grid<i32> {
+    let mut map = Grid::new(tree_grid.rows(), tree_grid.cols(), -1);
 
-    // Iterate through rows
-    for i in 0..size {
+    // Iterate through columns
+    for i in 0..tree_grid.cols() {
         let mut prev = -1;
 
-        // Iterate through Columns
-        // From Left to Right
-        for j in 0..size {
-            let current = tree_grid[j][i];
-            map[j][i] = prev;
+        // Iterate through Rows
+        // From Top to Bottom
+        for j in 0..tree_grid.rows() {
+            let current = tree_grid[(j, i)];
+            map[(j, i)] = prev;
             prev = current.max(prev);
         }
     }
@@ -105,36 +76,14 @@ pub fn bottom_map(tree_grid: &TreeGrid<i32>) -> TreeGrid<i32> {
     map
 }
 
-pub fn min_maps(mps: &[&TreeGrid<i32>]) -> TreeGrid<i32> {
-    let size = mps[0].len();
-    let mut map = create_empty_grid(size, -1);
-
-    // Iterate through rows
-    for i in 0..size {
-        // Iterate through Columns
-        // From Left to Right
-        for j in (0..size).rev() {
-            map[i][j] = mps.iter()
-                            .map(|x| x[i][j])
-                            .min().unwrap();
-        }
-    }
-
-    map
+pub fn min_maps(mps: &[&Grid<i32>]) -> Grid<i32> {
+    Grid::from_fn(mps[0].rows(), mps[0].cols(), |p| {
+        mps.iter()
+            .map(|x| x[p])
+            .min().unwrap()
+    })
 }
 
-pub fn apply_visibility_map(tree_grid: &TreeGrid<i32>, mm: &TreeGrid<i32>) -> TreeGrid<bool> {
-    let size = mm[0].len();
-    let mut map = create_empty_grid(size, false);
-
-    // Iterate through rows
-    for i in 0..size {
-        // Iterate through Columns
-        // From Left to Right
-        for j in (0..size).rev() {
-            map[i][j] = tree_grid[i][j] > mm[i][j];
-        }
-    }
-
-    map
+pub fn apply_visibility_map(tree_grid: &Grid<i32>, mm: &Grid<i32>) -> Grid<bool> {
+    Grid::from_fn(tree_grid.rows(), tree_grid.cols(), |p| tree_grid[p] > mm[p])
 }
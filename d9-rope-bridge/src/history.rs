@@ -1,29 +1,66 @@
-use std::collections::HashMap;
+use aoc_common::{
+    render::{render_fitted, render_region, Cell, Frame, Render},
+    sparse_grid::Bounds,
+    SparseGrid,
+};
 
-use crate::position::Position;
+use crate::{position::Position, rope_simulation::screen_pos};
 
-#[derive(Debug)]
+// How many times the tail visited each position
+#[derive(Debug, Default)]
 pub struct History {
-    map: HashMap<(i64, i64), usize>,
+    map: SparseGrid<usize>,
 }
 
 impl History {
     pub fn new() -> Self {
         History {
-            map: HashMap::new(),
+            map: SparseGrid::new(),
         }
     }
 
     pub fn add_history(&mut self, pos: &Position) {
-        *self.map.entry(pos.into_tuple()).or_insert(0) += 1;
+        *self.map.get_or_insert_with(pos.to_tuple(), || 0) += 1;
     }
 
     pub fn contains(&self, pos: &(i64, i64)) -> bool {
-        self.map.contains_key(pos)
+        self.map.contains(*pos)
     }
 
     pub fn count_visited_positions(&self) -> usize {
-        self.map.values().count()
+        self.map.len()
     }
 
+    pub fn grid(&self) -> &SparseGrid<usize> {
+        &self.map
+    }
+
+    // Visited positions on screen, and the start
+    fn visited(&self) -> SparseGrid<char> {
+        let mut frame = self
+            .map
+            .iter()
+            .map(|(pos, _)| (screen_pos(pos), '#'))
+            .collect::<SparseGrid<char>>();
+        frame.insert(screen_pos((0, 0)), 's');
+        frame
+    }
+
+    pub fn render_region(&self, region: Bounds) -> Frame {
+        render_region(&self.visited(), region, visited_cell)
+    }
+}
+
+impl Render for History {
+    fn render(&self) -> Frame {
+        render_fitted(&self.visited(), visited_cell)
+    }
+}
+
+fn visited_cell(c: Option<&char>) -> Cell {
+    match c {
+        Some('s') => Cell::new('s', [80, 140, 230]),
+        Some(&c) => Cell::new(c, [240, 220, 80]),
+        None => Cell::blank('.'),
+    }
 }
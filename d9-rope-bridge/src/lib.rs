@@ -0,0 +1,125 @@
+use std::io::BufRead;
+
+use aoc_common::{
+    render::{Frame, Recorder, Visualize},
+    stream::{LineParser, Stream},
+    ParseError, Part, Solution,
+};
+use direction::Direction;
+use position::Position;
+use r#move::Move;
+
+use crate::rope_simulation::RopeSimulation;
+
+// NOTE: Could improve struct visibility & project modile structure next time
+pub mod rope_simulation;
+pub mod rope;
+pub mod direction;
+pub mod position;
+pub mod r#move;
+pub mod history;
+
+pub fn parse_moves(input: &str) -> Result<Vec<Move>, ParseError> {
+    input.lines().enumerate().map(|(i, line)| parse_move(i + 1, line)).collect()
+}
+
+fn parse_move(line_number: usize, line: &str) -> Result<Move, ParseError> {
+    let mv: Move = match line.split_whitespace().collect::<Vec<&str>>()[..] {
+        [s, n] => {
+            let steps = n
+                .parse::<usize>()
+                .map_err(|_| ParseError::at_token(line_number, line, n, "Invalid step count"))?;
+
+            match s {
+                "R" => Move::new(Direction::Right, steps),
+                "L" => Move::new(Direction::Left, steps),
+                "U" => Move::new(Direction::Up, steps),
+                "D" => Move::new(Direction::Down, steps),
+                _ => return Err(ParseError::at_token(line_number, line, s, "Invalid direction")),
+            }
+        }
+        _ => return Err(ParseError::in_line(line_number, line, "Expected a direction and a step count")),
+    };
+
+    Ok(mv)
+}
+
+fn count_tail_positions(moves: &[Move], rope_size: usize) -> usize {
+    let mut sim = RopeSimulation::new(rope_size);
+
+    moves.iter().for_each(|mv| sim.apply_move(mv));
+
+    sim.count_revisited_positions()
+}
+
+// Only the rope and the positions its tail visited are kept
+fn stream_tail_positions(reader: impl BufRead, rope_size: usize) -> Result<usize, ParseError> {
+    let mut sim = RopeSimulation::new(rope_size);
+
+    for mv in LineParser::new(reader.lines(), parse_move) {
+        sim.apply_move(&mv?);
+    }
+
+    Ok(sim.count_revisited_positions())
+}
+
+pub struct RopeBridge;
+
+impl Solution for RopeBridge {
+    type Input = Vec<Move>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Vec<Move>, ParseError> {
+        parse_moves(input)
+    }
+
+    fn part_a(moves: &Vec<Move>) -> usize {
+        count_tail_positions(moves, 2)
+    }
+
+    fn part_b(moves: &Vec<Move>) -> usize {
+        count_tail_positions(moves, 10)
+    }
+}
+
+impl Stream for RopeBridge {
+    fn stream_a(reader: impl BufRead) -> Result<usize, ParseError> {
+        stream_tail_positions(reader, 2)
+    }
+
+    fn stream_b(reader: impl BufRead) -> Result<usize, ParseError> {
+        stream_tail_positions(reader, 10)
+    }
+}
+
+impl Visualize for RopeBridge {
+    // One frame per step of the head, then every position the tail visited
+    fn frames(moves: &Vec<Move>, part: Part, every: usize) -> Vec<Frame> {
+        let size = match part {
+            Part::A => 2,
+            Part::B => 10,
+        };
+
+        // A first run finds the area the rope covers, so that every frame
+        // shows the same area
+        let mut sim = RopeSimulation::new(size);
+        moves.iter().for_each(|mv| sim.apply_move(mv));
+        let region = sim.region();
+
+        let mut sim = RopeSimulation::new(size);
+        let mut recorder = Recorder::new(every);
+
+        for mv in moves {
+            let diff_pos: Position = (&mv.direction).into();
+            for _ in 0..mv.steps {
+                sim.step(&diff_pos);
+                recorder.record(|| sim.render_region(region));
+            }
+        }
+
+        let mut frames = recorder.finish(|| sim.render_region(region));
+        frames.push(sim.history().render_region(region));
+        frames
+    }
+}
@@ -18,14 +18,14 @@ impl Position {
     }
 
     pub fn add(&self, p: &Position) -> Position {
-        Position::op(&self, p, Add::add)
+        Position::op(self, p, Add::add)
     }
 
     pub fn sub(&self, p: &Position) -> Position {
-        Position::op(&self, p, Sub::sub)
+        Position::op(self, p, Sub::sub)
     }
 
-    pub fn into_tuple(&self) -> (i64, i64) {
+    pub fn to_tuple(&self) -> (i64, i64) {
         (self.row, self.col)
     }
 }
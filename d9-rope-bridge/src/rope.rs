@@ -63,7 +63,7 @@ impl Rope {
 
     pub fn update_position(&mut self, dhead: &Position) {
         // let mut prev_node = self.segments[0].clone();
-        self.segments[0] = self.segments[0].add(&dhead);
+        self.segments[0] = self.segments[0].add(dhead);
 
         for i in 1..self.segments.len() {
             let ht_diff = self.segments[i - 1].sub(&self.segments[i]);
@@ -89,6 +89,38 @@ impl Rope {
      */
 
     pub fn get_tail_position(&self) -> &Position {
-        &self.segments.last().unwrap()
+        self.segments.last().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod properties {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn direction() -> impl Strategy<Value = Direction> {
+        prop_oneof![
+            Just(Direction::Right),
+            Just(Direction::Left),
+            Just(Direction::Up),
+            Just(Direction::Down),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn segments_stay_adjacent(size in 1..12usize, steps in prop::collection::vec(direction(), 0..200)) {
+            let mut rope = Rope::new(size);
+
+            for direction in &steps {
+                rope.update_position(&direction.into());
+
+                for pair in rope.segments.windows(2) {
+                    let diff = pair[0].sub(&pair[1]);
+                    prop_assert!(diff.row.abs() <= 1 && diff.col.abs() <= 1, "{:?} are apart", pair);
+                }
+            }
+        }
     }
 }
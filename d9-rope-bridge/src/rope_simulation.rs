@@ -1,3 +1,10 @@
+use aoc_common::{
+    render::{render_fitted, render_region, Cell, Frame, Render},
+    sparse_grid::Bounds,
+    SparseGrid,
+};
+use tracing::{debug, trace};
+
 use crate::{history::History, position::Position, r#move::Move, rope::Rope};
 
 pub struct RopeSimulation {
@@ -16,80 +23,83 @@ impl RopeSimulation {
     pub fn apply_move(&mut self, Move { direction, steps }: &Move) {
         let diff_pos: Position = direction.into();
 
-        println!("{direction:?}: {steps}");
-
         for _ in 0..*steps {
-            self.rope.update_position(&diff_pos);
-            self.history.add_history(self.rope.get_tail_position());
-            // self.blit_state();
+            self.step(&diff_pos);
         }
-        self.blit_state();
+
+        trace!("after {steps} steps {direction:?}:\n{}", self.render());
+    }
+
+    // Moves the head by `diff_pos`, which should be a single step
+    pub fn step(&mut self, diff_pos: &Position) {
+        self.rope.update_position(diff_pos);
+        self.history.add_history(self.rope.get_tail_position());
+        trace!(segments = ?self.rope.segments, "step");
     }
 
     pub fn count_revisited_positions(&self) -> usize {
-        self.blit_history();
+        debug!("positions visited by the tail:\n{}", self.history.render());
         self.history.count_visited_positions()
     }
 
-    fn blit_state(&self) {
-        let debug = false;
-        if !debug {
-            return;
-        }
+    pub fn history(&self) -> &History {
+        &self.history
+    }
+
+    // Screen area covering everywhere the rope went so far, as the head is
+    // never further than the rope's length from the tail
+    pub fn region(&self) -> Bounds {
+        let reach = self.rope.segments.len() as i64;
 
-        println!("{:?}", self.rope.segments);
-
-        for i in (-5..=15).rev() {
-            for j in -11..=11 {
-                let mut found = false;
-
-                for (k, pos) in self.rope.segments.iter().enumerate() {
-                    if pos.into_tuple() == (i as i64, j) {
-                        if k == 0 {
-                            print!("H");
-                        } else if k == self.rope.segments.len() - 1 {
-                            print!("t");
-                        } else {
-                            print!("{k}");
-                        }
-                        found = true;
-                        break;
-                    }
-                }
-
-                if !found {
-                    if (i, j) == (0, 0) {
-                        print!("s");
-                    } else {
-                        print!(".");
-                    }
-                }
-            }
-            println!();
+        match self.history.grid().bounds() {
+            Some(Bounds { min, max }) => Bounds::new(screen_pos(min), screen_pos(max)),
+            None => Bounds::new((0, 0), (0, 0)),
         }
-        println!();
+        .including((0, 0))
+        .expanded(reach)
     }
 
-    fn blit_history(&self) {
-        let debug = true;
-        if !debug {
-            return;
-        }
+    // Drawn from the tail up, so the head ends up on top of the others
+    fn state(&self) -> SparseGrid<char> {
+        let mut frame = SparseGrid::new();
+        frame.insert(screen_pos((0, 0)), 's');
 
-        println!("{:?}", self.rope.segments);
-
-        for i in (-5..=15).rev() {
-            for j in -11..=11 {
-                if (i, j) == (0, 0) {
-                    print!("s");
-                } else if self.history.contains(&(i, j)) {
-                    print!("#");
-                } else {
-                    print!(".");
-                }
-            }
-            println!();
+        let tail = self.rope.segments.len() - 1;
+        for (k, pos) in self.rope.segments.iter().enumerate().rev() {
+            let c = match k {
+                0 => 'H',
+                k if k == tail => 't',
+                k => char::from_digit(k as u32 % 10, 10).unwrap_or('?'),
+            };
+            frame.insert(screen_pos(pos.to_tuple()), c);
         }
-        println!();
+
+        frame
     }
+
+    pub fn render_region(&self, region: Bounds) -> Frame {
+        render_region(&self.state(), region, rope_cell)
+    }
+
+}
+
+impl Render for RopeSimulation {
+    fn render(&self) -> Frame {
+        render_fitted(&self.state(), rope_cell)
+    }
+}
+
+fn rope_cell(c: Option<&char>) -> Cell {
+    match c {
+        Some('H') => Cell::new('H', [230, 60, 60]),
+        Some('t') => Cell::new('t', [240, 220, 80]),
+        Some('s') => Cell::new('s', [80, 140, 230]),
+        Some(&c) => Cell::new(c, [240, 150, 60]),
+        None => Cell::blank('.'),
+    }
+}
+
+// Rows grow upwards in the simulation, but downwards on screen
+pub(crate) fn screen_pos((row, col): (i64, i64)) -> (i64, i64) {
+    (-row, col)
 }
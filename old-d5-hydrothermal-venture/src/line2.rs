@@ -1,9 +1,10 @@
 // I realized a much faster and simpler method that takes advantage of the horizontal/verticality...
 // So this isn't needed, but it was a good read ;(
+// Kept to check the grid against in the differential tests.
 
-fn is_non_diagonal((x1, y1, x2, y2): &Quaduple<i32>) -> bool {
-    (y1 == y2) || (x1 == x2)
-}
+use std::cmp::{max, min};
+
+use crate::Quaduple;
 
 // Calculate the direction of the rotation created by:
 //
@@ -40,7 +41,7 @@ fn is_on_line(p1: (i32, i32), p2: (i32, i32), (x, y): (i32, i32)) -> bool {
     x1 <= x && x <= x2 && y1 <= y && y <= y2
 }
 
-fn do_points_intersect(l1: &Quaduple<i32>, l2: &Quaduple<i32>) -> bool {
+pub fn do_points_intersect(l1: &Quaduple<i32>, l2: &Quaduple<i32>) -> bool {
     let l1p1 = (l1.0, l1.1);
     let l1p2 = (l1.2, l1.3);
     let l2p1 = (l2.0, l2.1);
@@ -74,5 +75,5 @@ fn do_points_intersect(l1: &Quaduple<i32>, l2: &Quaduple<i32>) -> bool {
         return true;
     }
 
-    return false;
+    false
 }